@@ -12,7 +12,7 @@ use rand::rngs::OsRng;
 
 fn random_message() -> [u8; 100] {
     let mut msg = [0u8; 100];
-    (&mut OsRng::default()).fill_bytes(&mut msg);
+    OsRng.fill_bytes(&mut msg);
     msg
 }
 
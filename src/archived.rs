@@ -0,0 +1,95 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Verification and aggregation directly on archived keys and signatures.
+//!
+//! The archived types share the layout of their points with the owned types,
+//! so they are read in place rather than decoded from bytes. Points are still
+//! checked for validity before use, since archived data may come from
+//! untrusted sources.
+
+use dusk_bls12_381::{G1Affine, G1Projective, G2Affine};
+use rkyv::{Deserialize, Infallible};
+
+use crate::hash::h0;
+use crate::keys::public::{aggregate_keys, verify_point};
+use crate::{
+    ArchivedMultisigPublicKey, ArchivedMultisigSignature, ArchivedPublicKey,
+    ArchivedSignature, Error, MultisigPublicKey, MultisigSignature, PublicKey,
+};
+
+fn public_key(pk: &ArchivedPublicKey) -> PublicKey {
+    let Ok(pk) = pk.deserialize(&mut Infallible);
+    pk
+}
+
+fn g2(point: &dusk_bls12_381::ArchivedG2Affine) -> G2Affine {
+    let Ok(point) = point.deserialize(&mut Infallible);
+    point
+}
+
+fn g1(point: &dusk_bls12_381::ArchivedG1Affine) -> G1Affine {
+    let Ok(point) = point.deserialize(&mut Infallible);
+    point
+}
+
+impl ArchivedPublicKey {
+    /// Verify an archived [`Signature`](crate::Signature), see [`PublicKey::verify`].
+    pub fn verify(
+        &self,
+        sig: &ArchivedSignature,
+        msg: &[u8],
+    ) -> Result<(), Error> {
+        verify_point(&g2(&self.0), &g1(&sig.0), &h0(msg))
+    }
+}
+
+impl ArchivedMultisigPublicKey {
+    /// Verify an archived [`MultisigSignature`], see
+    /// [`MultisigPublicKey::verify`].
+    pub fn verify(
+        &self,
+        sig: &ArchivedMultisigSignature,
+        msg: &[u8],
+    ) -> Result<(), Error> {
+        verify_point(&g2(&self.0), &g1(&sig.0), &h0(msg))
+    }
+}
+
+impl MultisigPublicKey {
+    /// Aggregate a set of archived [`PublicKey`]s, see
+    /// [`MultisigPublicKey::aggregate`].
+    pub fn aggregate_archived(
+        pks: &[ArchivedPublicKey],
+    ) -> Result<Self, Error> {
+        aggregate_keys(pks, |pk| g2(&pk.0), |pk| public_key(pk).pk_t(), false)
+            .map(Self)
+    }
+
+    /// Aggregate a set of distinct archived [`PublicKey`]s, see
+    /// [`MultisigPublicKey::aggregate_distinct`].
+    pub fn aggregate_archived_distinct(
+        pks: &[ArchivedPublicKey],
+    ) -> Result<Self, Error> {
+        aggregate_keys(pks, |pk| g2(&pk.0), |pk| public_key(pk).pk_t(), true)
+            .map(Self)
+    }
+}
+
+impl MultisigSignature {
+    /// Aggregate a set of archived signatures into this one, see
+    /// [`MultisigSignature::aggregate`].
+    pub fn aggregate_archived(
+        &self,
+        sigs: &[ArchivedMultisigSignature],
+    ) -> Self {
+        Self(
+            sigs.iter()
+                .fold(G1Projective::from(self.0), |acc, sig| acc + g1(&sig.0))
+                .into(),
+        )
+    }
+}
@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::Error;
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Decode a list of encodings, in parallel with the `parallel` feature.
+///
+/// Only parallelism is provided, there is no batch inversion: decompressing a
+/// point takes a square root and checking its subgroup a scalar
+/// multiplication, neither of which can be shared across a batch.
+///
+/// On failure, returns the index of the first encoding that failed to decode,
+/// together with its error.
+pub(crate) fn decode_batch<T: Send, const N: usize>(
+    bytes: &[[u8; N]],
+    decode: fn(&[u8; N]) -> Result<T, Error>,
+) -> Result<Vec<T>, (usize, Error)> {
+    #[cfg(not(feature = "parallel"))]
+    let decoded = bytes
+        .iter()
+        .enumerate()
+        .map(|(index, bytes)| decode(bytes).map_err(|err| (index, err)))
+        .collect();
+
+    // Every encoding is decoded at most once, and encodings after the lowest
+    // failing index found so far are skipped. The skipped ones all come after
+    // a failure, so the first error is still found in order.
+    #[cfg(feature = "parallel")]
+    let decoded = {
+        let first_error = AtomicUsize::new(usize::MAX);
+        bytes
+            .par_iter()
+            .enumerate()
+            .map(|(index, bytes)| {
+                if index > first_error.load(Ordering::Relaxed) {
+                    return None;
+                }
+                let res = decode(bytes);
+                if res.is_err() {
+                    first_error.fetch_min(index, Ordering::Relaxed);
+                }
+                Some(res)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .enumerate()
+            .map_while(|(index, res)| res.map(|res| (index, res)))
+            .map(|(index, res)| res.map_err(|err| (index, err)))
+            .collect()
+    };
+
+    decoded
+}
@@ -0,0 +1,112 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Verification of drand randomness beacons.
+//!
+//! drand networks using the `bls-unchained-g1-rfc9380` scheme, such as
+//! quicknet, sign rounds with G1 signatures under a G2 group public key, the
+//! layout of [`Signature`] and [`PublicKey`]. A round is signed as:
+//!
+//! ```text
+//! sig = sign(SHA-256(round))
+//! ```
+//!
+//! with the round as a big-endian `u64` and the RFC 9380 hash to G1 under the
+//! drand DST. The randomness of a round is the SHA-256 hash of its signature.
+//! As the signature of a round is the identity key of the round in the
+//! [`ibe`](crate::ibe) scheme, [`timelock_encrypt`] encrypts messages that can
+//! only be decrypted once the round is reached.
+//!
+//! The chained drand scheme `pedersen-bls-chained` swaps the groups, with G1
+//! group keys and G2 signatures, and can't be represented by the types of
+//! this crate, so only unchained beacons are supported.
+//!
+//! Like [`ibe`](crate::ibe), the module is made of free functions with names
+//! like `randomness` that would be ambiguous at the crate root, so it is used
+//! through the `beacon` module rather than re-exported.
+//!
+//! ```
+//! use bls12_381_bls::beacon;
+//! use bls12_381_bls::{PublicKey, Signature};
+//! use dusk_bytes::Serializable;
+//!
+//! // drand quicknet
+//! let pk = hex::decode(
+//!     "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c\
+//!      8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb\
+//!      5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a",
+//! )
+//! .unwrap();
+//! let pk = PublicKey::from_bytes(&pk.try_into().unwrap()).unwrap();
+//!
+//! let sig = hex::decode(
+//!     "b44679b9a59af2ec876b1a6b1ad52ea9b1615fc3982b19576350f93447cb1125\
+//!      e342b73a8dd2bacbe47e4b6b63ed5e39",
+//! )
+//! .unwrap();
+//! let sig = Signature::from_bytes(&sig.try_into().unwrap()).unwrap();
+//!
+//! assert!(beacon::verify_unchained(&pk, 1000, &sig).is_ok());
+//! assert_eq!(
+//!     hex::encode(beacon::randomness(&sig)),
+//!     "fe290beca10872ef2fb164d2aa4442de4566183ec51c56ff3cd603d930e54fdd"
+//! );
+//! ```
+
+use dusk_bls12_381::G1Affine;
+use dusk_bytes::Serializable;
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+use crate::hash::hash_to_g1;
+use crate::ibe::{Ciphertext, encrypt_to_point};
+use crate::keys::public::verify_point;
+use crate::{Error, PublicKey, Signature};
+
+/// DST of the drand schemes with G1 signatures.
+pub const DRAND_G1_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_NUL_";
+
+fn hash_round(round: u64) -> G1Affine {
+    hash_to_g1(&Sha256::digest(&round.to_be_bytes()), DRAND_G1_DST)
+}
+
+/// Verify the signature of an unchained beacon `round` under the group
+/// public key `pk`.
+///
+/// # Errors
+///
+/// Errors with [`Error::InvalidSignature`] if the signature doesn't verify,
+/// or with the point errors of [`PublicKey::verify`].
+pub fn verify_unchained(
+    pk: &PublicKey,
+    round: u64,
+    sig: &Signature,
+) -> Result<(), Error> {
+    verify_point(&pk.0, &sig.0, &hash_round(round))
+}
+
+/// Derive the randomness of a beacon from its signature.
+///
+/// The signature must be verified first, with [`verify_unchained`].
+pub fn randomness(sig: &Signature) -> [u8; 32] {
+    Sha256::digest(&sig.to_bytes()).into()
+}
+
+/// Encrypt `msg` to an unchained beacon `round` of the network with group
+/// public key `pk`.
+///
+/// The ciphertext can be decrypted with [`ibe::decrypt`] and the signature of
+/// the round, once the network publishes it.
+///
+/// [`ibe::decrypt`]: crate::ibe::decrypt
+pub fn timelock_encrypt<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    pk: &PublicKey,
+    round: u64,
+    msg: &[u8],
+) -> Ciphertext {
+    encrypt_to_point(rng, pk, &hash_round(round), msg)
+}
@@ -0,0 +1,204 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Blind BLS signatures.
+//!
+//! The user blinds the hash of a message with a random factor, the signer
+//! signs the blinded point without learning the message, and the user
+//! unblinds the result into a plain [`Signature`]:
+//!
+//! ```text
+//! user:   B  = r * H(msg)
+//! signer: S' = sk' * B
+//! user:   S  = r^-1 * S'
+//! ```
+//!
+//! Since every valid point of G1 is the blinding of any message by some
+//! factor, the signer can't learn anything about the message, and no proof
+//! about the request can tie it to a message without revealing it. The
+//! signer only rejects invalid, identity and small subgroup points, and the
+//! user checks the blind signature before unblinding it.
+//!
+//! Signing a blinded point therefore multiplies any point of the requester's
+//! choice by the signing key, which would let requesters sign messages under
+//! any domain separation tag, extract [`IdentitySecretKey`]s, or compute VRF
+//! outputs of the key. Blind
+//! requests are hence never signed with the secret key itself, but with the
+//! dedicated key `sk'` derived from it under a blind signing DST, whose public
+//! key is [`SecretKey::blind_public_key`]. The derived key must not be used
+//! for anything but blind signing, and unblinded signatures only verify
+//! under the blind public key.
+//!
+//! [`IdentitySecretKey`]: crate::IdentitySecretKey
+//!
+//! ```
+//! use bls12_381_bls::{BlindingFactor, SecretKey};
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(0xb11d);
+//! let sk = SecretKey::random(&mut rng);
+//! let pk = sk.blind_public_key();
+//!
+//! let factor = BlindingFactor::random(&mut rng);
+//! let blinded = factor.blind(b"voucher 1337");
+//!
+//! let blind_sig = sk.sign_blinded(&blinded).unwrap();
+//!
+//! let sig = factor.unblind(&pk, b"voucher 1337", &blind_sig).unwrap();
+//! assert!(pk.verify(&sig, b"voucher 1337").is_ok());
+//! ```
+
+use core::fmt;
+
+use dusk_bls12_381::hash_to_curve::{ExpandMsgXmd, HashToField};
+use dusk_bls12_381::{BlsScalar, G1Affine};
+use dusk_bytes::Serializable;
+use ff::Field;
+use rand_core::{CryptoRng, RngCore};
+use sha2::Sha256;
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+use crate::hash::h0;
+use crate::keys::public::verify_point;
+use crate::signatures::{check, g1_from_bytes, is_valid};
+use crate::{Error, PublicKey, SecretKey, Signature};
+
+const BLIND_KEY_DST: &[u8] = b"BLS_BLIND_SIGNING_KEY_XMD:SHA-256_DUSK_V1";
+
+/// The secret factor blinding a message, kept by the user between
+/// [`BlindingFactor::blind`] and [`BlindingFactor::unblind`].
+///
+/// The factor is zeroized on drop and redacted from the [`Debug`] output. It
+/// can't be zeroized earlier, so it is never zero while it can be used.
+#[derive(Clone, ZeroizeOnDrop)]
+pub struct BlindingFactor(BlsScalar);
+
+impl fmt::Debug for BlindingFactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BlindingFactor(<redacted>)")
+    }
+}
+
+impl BlindingFactor {
+    /// Generate a new random, non-zero [`BlindingFactor`].
+    pub fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        loop {
+            let r = BlsScalar::random(&mut *rng);
+            if r != BlsScalar::zero() {
+                return Self(r);
+            }
+        }
+    }
+
+    /// Blind `msg` for signing with [`SecretKey::sign_blinded`].
+    pub fn blind(&self, msg: &[u8]) -> BlindedMessage {
+        BlindedMessage((h0(msg) * self.0).into())
+    }
+
+    /// Unblind the signature on `msg` returned by the signer with blind
+    /// public key `pk`, see [`SecretKey::blind_public_key`].
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::InvalidSignature`] if the blind signature isn't
+    /// the signature of `pk` on the message blinded by `self`, or with the
+    /// point errors of [`PublicKey::verify`].
+    pub fn unblind(
+        &self,
+        pk: &PublicKey,
+        msg: &[u8],
+        sig: &BlindSignature,
+    ) -> Result<Signature, Error> {
+        let blinded = self.blind(msg);
+        verify_point(&pk.0, &sig.0, &blinded.0)?;
+
+        let inverse = self.0.invert().expect("blinding factor is non-zero");
+        Ok(Signature((sig.0 * inverse).into()))
+    }
+}
+
+/// A message blinded by a [`BlindingFactor`], sent to the signer.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BlindedMessage(G1Affine);
+
+impl BlindedMessage {
+    /// Returns true if the inner point is valid, see
+    /// [`Signature::is_valid`].
+    pub fn is_valid(&self) -> bool {
+        is_valid(&self.0)
+    }
+}
+
+impl Serializable<48> for BlindedMessage {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(g1_from_bytes(bytes)?))
+    }
+}
+
+/// The signature on a [`BlindedMessage`], returned to the user.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BlindSignature(G1Affine);
+
+impl Serializable<48> for BlindSignature {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(g1_from_bytes(bytes)?))
+    }
+}
+
+impl SecretKey {
+    /// Derive the dedicated key blind requests are signed with.
+    fn blind_signing_key(&self) -> SecretKey {
+        let bytes = Zeroizing::new(self.0.to_bytes());
+        let mut key = SecretKey::default();
+        BlsScalar::hash_to_field::<ExpandMsgXmd<Sha256>>(
+            &bytes[..],
+            BLIND_KEY_DST,
+            core::slice::from_mut(&mut key.0),
+        );
+        key
+    }
+
+    /// The public key that signatures unblinded from
+    /// [`SecretKey::sign_blinded`] verify under.
+    ///
+    /// It belongs to a key derived from `self` for blind signing only, and is
+    /// unrelated to `PublicKey::from(self)`.
+    pub fn blind_public_key(&self) -> PublicKey {
+        PublicKey::from(&self.blind_signing_key())
+    }
+
+    /// Sign a message blinded by the user, without learning the message.
+    ///
+    /// The blinded point is signed with the dedicated key of
+    /// [`SecretKey::blind_public_key`], never with `self`, since the signer
+    /// can't tell which point it multiplies.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::InvalidPoint`], [`Error::IdentityPoint`] or
+    /// [`Error::NotInSubgroup`] if the blinded message is malformed.
+    pub fn sign_blinded(
+        &self,
+        blinded: &BlindedMessage,
+    ) -> Result<BlindSignature, Error> {
+        check(&blinded.0)?;
+        let key = self.blind_signing_key();
+        Ok(BlindSignature((blinded.0 * key.0).into()))
+    }
+}
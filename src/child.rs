@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Non-hardened derivation of child keys.
+//!
+//! A child key is the parent key shifted by a public tweak, derived with HKDF
+//! from the parent [`PublicKey`], a chain code and an index:
+//!
+//! ```text
+//! t        = HKDF(salt = chain_code, IKM = pk, info = DST || index)
+//! child_sk = sk + t
+//! child_pk = pk + t * G2
+//! ```
+//!
+//! so that children of a [`PublicKey`] can be derived without its
+//! [`SecretKey`], and match the children derived from the [`SecretKey`].
+
+use dusk_bls12_381::{BlsScalar, G2Affine, G2Projective};
+use dusk_bytes::Serializable;
+
+use crate::kdf::hkdf_scalar;
+use crate::{PublicKey, SecretKey};
+
+const CHILD_DST: &[u8] = b"BLS_CHILD_KEY_TWEAK_DUSK_V1";
+
+impl PublicKey {
+    /// Compute the tweak between the key and its child at `index` under
+    /// `chain_code`.
+    pub fn child_tweak(&self, chain_code: &[u8; 32], index: u32) -> BlsScalar {
+        hkdf_scalar(
+            chain_code,
+            &[&self.to_bytes()],
+            &[CHILD_DST, &index.to_be_bytes()],
+        )
+    }
+
+    /// Derive the child public key at `index` under `chain_code`.
+    ///
+    /// The child matches the key derived by [`SecretKey::derive_child`] from
+    /// the secret key of `self`.
+    ///
+    /// # Security
+    ///
+    /// Anyone knowing the parent public key and the chain code knows the
+    /// tweaks, and with them the linear relations between the parent and all
+    /// of its children:
+    ///
+    /// - The keys are linkable, unless the chain code is kept secret.
+    /// - A signature under one child on a message can be turned into a
+    ///   signature under the parent, or under any sibling, on the same message.
+    ///   When related keys are aggregated into a [`MultisigPublicKey`], one
+    ///   signer holding the parent key can produce the signatures of all of
+    ///   them, so the aggregate proves no more than a single signature.
+    /// - Leaking a child secret key together with the chain code leaks the
+    ///   parent secret key, and with it every other child.
+    ///
+    /// [`MultisigPublicKey`]: crate::MultisigPublicKey
+    pub fn derive_child(&self, chain_code: &[u8; 32], index: u32) -> Self {
+        let t = self.child_tweak(chain_code, index);
+        let child = G2Projective::from(self.0) + G2Affine::generator() * t;
+        Self(child.into())
+    }
+}
+
+impl SecretKey {
+    /// Derive the child secret key at `index` under `chain_code`.
+    ///
+    /// See [`PublicKey::derive_child`], including its security notes.
+    pub fn derive_child(&self, chain_code: &[u8; 32], index: u32) -> Self {
+        let t = PublicKey::from(self).child_tweak(chain_code, index);
+        Self(self.0 + t)
+    }
+}
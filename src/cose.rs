@@ -0,0 +1,217 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! COSE key representations of BLS12-381 G2 keys, and `COSE_Sign1`
+//! signing.
+//!
+//! Keys use the same layout as the [`Jwk`](crate::Jwk) representations, with
+//! the `Bls12381G2` curve identifier of the IETF draft and the byte strings
+//! stored without base64.
+//!
+//! There is no registered COSE algorithm for BLS signatures, so `COSE_Sign1`
+//! messages carry the private use [`COSE_ALGORITHM`] in their protected `alg`
+//! header, and messages without it are rejected. The signature is the
+//! compressed [`Signature`] of the `Sig_structure`.
+
+use alloc::vec::Vec;
+
+use coset::cbor::value::Value;
+use coset::iana::{self, Ec2KeyParameter, OkpKeyParameter};
+use coset::{Algorithm, CoseKey, CoseSign1, CoseSign1Builder, KeyType, Label};
+use dusk_bytes::{BadLength, Serializable};
+use zeroize::Zeroizing;
+
+use crate::key_params::{
+    COORDINATE_SIZE, coordinates, from_coordinates, secret_key,
+};
+use crate::{Error, ExposedSecretKey, PublicKey, SecretKey, Signature};
+
+/// COSE elliptic curve identifier of `Bls12381G2`.
+const CURVE: i64 = 14;
+
+/// COSE algorithm identifier of the signatures of this crate, set in the
+/// protected `alg` header of `COSE_Sign1` messages.
+///
+/// The identifier is taken from the private use range, since no algorithm is
+/// registered for BLS signatures, so it is only understood by this crate.
+pub const COSE_ALGORITHM: i64 = -65537;
+
+fn algorithm() -> Algorithm {
+    Algorithm::PrivateUse(COSE_ALGORITHM)
+}
+
+// The parameter labels are shared between `OKP` and `EC2` keys.
+const CRV: i64 = OkpKeyParameter::Crv as i64;
+const X: i64 = OkpKeyParameter::X as i64;
+const Y: i64 = Ec2KeyParameter::Y as i64;
+const D: i64 = OkpKeyParameter::D as i64;
+
+fn key(kty: iana::KeyType, params: Vec<(i64, Vec<u8>)>) -> CoseKey {
+    let mut key = CoseKey {
+        kty: KeyType::Assigned(kty),
+        params: alloc::vec![(Label::Int(CRV), Value::from(CURVE))],
+        ..Default::default()
+    };
+    key.params.extend(
+        params
+            .into_iter()
+            .map(|(label, bytes)| (Label::Int(label), Value::Bytes(bytes))),
+    );
+    key
+}
+
+fn okp(pk: &PublicKey) -> CoseKey {
+    key(iana::KeyType::OKP, alloc::vec![(X, pk.to_bytes().to_vec())])
+}
+
+fn ec2(pk: &PublicKey) -> CoseKey {
+    let (x, y) = coordinates(pk);
+    key(
+        iana::KeyType::EC2,
+        alloc::vec![(X, x.to_vec()), (Y, y.to_vec())],
+    )
+}
+
+fn with_secret(mut key: CoseKey, sk: &ExposedSecretKey) -> CoseKey {
+    let d = crate::key_params::with_secret(sk, <[u8]>::to_vec);
+    key.params.push((Label::Int(D), Value::Bytes(d)));
+    key
+}
+
+fn param(key: &CoseKey, label: i64) -> Option<&Value> {
+    key.params
+        .iter()
+        .find(|(l, _)| *l == Label::Int(label))
+        .map(|(_, value)| value)
+}
+
+fn bytes<const N: usize>(key: &CoseKey, label: i64) -> Result<[u8; N], Error> {
+    match param(key, label) {
+        Some(Value::Bytes(bytes)) => bytes[..]
+            .try_into()
+            .map_err(|_| Error::bad_length(bytes.len(), N)),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
+
+fn public_key(key: &CoseKey) -> Result<PublicKey, Error> {
+    match param(key, CRV) {
+        Some(Value::Integer(crv)) if i128::from(*crv) == i128::from(CURVE) => {}
+        Some(_) => return Err(Error::UnsupportedKey),
+        None => return Err(Error::InvalidEncoding),
+    }
+    match &key.kty {
+        KeyType::Assigned(iana::KeyType::OKP) => {
+            PublicKey::from_bytes(&bytes(key, X)?)
+        }
+        KeyType::Assigned(iana::KeyType::EC2) => {
+            let x: [u8; COORDINATE_SIZE] = bytes(key, X)?;
+            from_coordinates(&x, &bytes(key, Y)?)
+        }
+        _ => Err(Error::UnsupportedKey),
+    }
+}
+
+impl PublicKey {
+    /// Represent the key as an `OKP` [`CoseKey`].
+    pub fn to_cose_key(&self) -> CoseKey {
+        okp(self)
+    }
+
+    /// Represent the key as an `EC2` [`CoseKey`].
+    pub fn to_cose_key_ec2(&self) -> CoseKey {
+        ec2(self)
+    }
+
+    /// Read the public key of an `OKP` or `EC2` [`CoseKey`].
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::UnsupportedKey`] if the key has another type or
+    /// curve, and with [`Error::InvalidEncoding`] if the parameters of the
+    /// key are missing.
+    pub fn from_cose_key(key: &CoseKey) -> Result<Self, Error> {
+        public_key(key)
+    }
+
+    /// Verify the signature of a `COSE_Sign1` message, with the given
+    /// externally supplied data.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::UnsupportedAlgorithm`] if the protected `alg`
+    /// header isn't [`COSE_ALGORITHM`], with [`Error::InvalidSignature`] if
+    /// the signature doesn't verify, or with the error of
+    /// [`Signature::from_bytes`] if it isn't a valid signature encoding.
+    pub fn verify_cose(
+        &self,
+        sign1: &CoseSign1,
+        aad: &[u8],
+    ) -> Result<(), Error> {
+        if sign1.protected.header.alg != Some(algorithm()) {
+            return Err(Error::UnsupportedAlgorithm);
+        }
+        sign1.verify_signature(aad, |sig, tbs| {
+            let sig: [u8; Signature::SIZE] = sig
+                .try_into()
+                .map_err(|_| Error::bad_length(sig.len(), Signature::SIZE))?;
+            self.verify(&Signature::from_bytes(&sig)?, tbs)
+        })
+    }
+}
+
+impl ExposedSecretKey<'_> {
+    /// Represent the key, together with its public key, as an `OKP`
+    /// [`CoseKey`].
+    ///
+    /// The [`CoseKey`] is not zeroized on drop, the caller is responsible
+    /// for clearing the secret parameter.
+    pub fn to_cose_key(&self) -> CoseKey {
+        with_secret(okp(&PublicKey::from(self.0)), self)
+    }
+
+    /// Represent the key, together with its public key, as an `EC2`
+    /// [`CoseKey`].
+    ///
+    /// The [`CoseKey`] is not zeroized on drop, the caller is responsible
+    /// for clearing the secret parameter.
+    pub fn to_cose_key_ec2(&self) -> CoseKey {
+        with_secret(ec2(&PublicKey::from(self.0)), self)
+    }
+}
+
+impl SecretKey {
+    /// Read the secret key of an `OKP` or `EC2` [`CoseKey`].
+    ///
+    /// # Errors
+    ///
+    /// On top of the errors of [`PublicKey::from_cose_key`], errors with
+    /// [`Error::InvalidEncoding`] if the key has no secret, and with
+    /// [`Error::KeyMismatch`] if the public key doesn't belong to the secret
+    /// key.
+    pub fn from_cose_key(key: &CoseKey) -> Result<Self, Error> {
+        let pk = public_key(key)?;
+        secret_key(&pk, Zeroizing::new(bytes(key, D)?))
+    }
+
+    /// Sign the `COSE_Sign1` message being built, with the given externally
+    /// supplied data.
+    ///
+    /// The headers and payload are taken from `builder`. The protected `alg`
+    /// header is set to [`COSE_ALGORITHM`], replacing any other algorithm,
+    /// and the signature is set.
+    pub fn sign_cose(
+        &self,
+        builder: CoseSign1Builder,
+        aad: &[u8],
+    ) -> CoseSign1 {
+        let mut sign1 = builder.build();
+        sign1.protected.header.alg = Some(algorithm());
+        sign1.protected.original_data = None;
+        sign1.signature = self.sign(&sign1.tbs_data(aad)).to_bytes().to_vec();
+        sign1
+    }
+}
@@ -0,0 +1,166 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Multicodec, multibase and `did:key` names for public keys.
+//!
+//! A [`PublicKey`] is tagged with the `bls12_381-g2-pub` multicodec (`0xeb`),
+//! encoded as base58btc multibase (`z` prefix), and named by prepending
+//! `did:key:` to the multibase string.
+
+use alloc::format;
+use alloc::string::String;
+
+use dusk_bytes::{BadLength, Serializable};
+
+use crate::{Error, PublicKey};
+
+/// The unsigned varint of the `bls12_381-g2-pub` multicodec, `0xeb`.
+const MULTICODEC: [u8; 2] = [0xeb, 0x01];
+
+/// Multibase prefix of base58btc.
+const BASE58BTC: char = 'z';
+
+const DID_KEY: &str = "did:key:";
+
+/// Verification method type of multibase encoded keys.
+const MULTIKEY: &str = "Multikey";
+
+impl PublicKey {
+    /// Size of the multicodec encoding of a [`PublicKey`].
+    pub const MULTICODEC_SIZE: usize = MULTICODEC.len() + Self::SIZE;
+
+    /// Encode the key prefixed with the `bls12_381-g2-pub` multicodec.
+    pub fn to_multicodec(&self) -> [u8; Self::MULTICODEC_SIZE] {
+        let mut bytes = [0u8; Self::MULTICODEC_SIZE];
+        bytes[..MULTICODEC.len()].copy_from_slice(&MULTICODEC);
+        bytes[MULTICODEC.len()..].copy_from_slice(&self.to_bytes());
+        bytes
+    }
+
+    /// Decode a key prefixed with the `bls12_381-g2-pub` multicodec.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::UnsupportedKey`] if the multicodec is not
+    /// `bls12_381-g2-pub`.
+    pub fn from_multicodec(bytes: &[u8]) -> Result<Self, Error> {
+        let key = bytes
+            .strip_prefix(&MULTICODEC)
+            .ok_or(Error::UnsupportedKey)?;
+        let key = key
+            .try_into()
+            .map_err(|_| Error::bad_length(key.len(), Self::SIZE))?;
+        Self::from_bytes(key)
+    }
+
+    /// Encode the multicodec encoding as a base58btc multibase string.
+    pub fn to_multibase(&self) -> String {
+        let encoded = bs58::encode(self.to_multicodec()).into_string();
+        format!("{BASE58BTC}{encoded}")
+    }
+
+    /// Decode a base58btc multibase string of the multicodec encoding.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::InvalidEncoding`] if the string isn't base58btc
+    /// multibase, and otherwise as [`PublicKey::from_multicodec`].
+    pub fn from_multibase(s: &str) -> Result<Self, Error> {
+        let encoded =
+            s.strip_prefix(BASE58BTC).ok_or(Error::InvalidEncoding)?;
+        let bytes = bs58::decode(encoded)
+            .into_vec()
+            .map_err(|_| Error::InvalidEncoding)?;
+        Self::from_multicodec(&bytes)
+    }
+
+    /// Name the key with a `did:key` identifier.
+    pub fn to_did_key(&self) -> String {
+        format!("{DID_KEY}{}", self.to_multibase())
+    }
+
+    /// Parse a `did:key` identifier.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::WrongPrefix`] if the identifier isn't a
+    /// `did:key`, and otherwise as [`PublicKey::from_multibase`].
+    pub fn from_did_key(did: &str) -> Result<Self, Error> {
+        let multibase = did.strip_prefix(DID_KEY).ok_or(Error::WrongPrefix)?;
+        Self::from_multibase(multibase)
+    }
+}
+
+/// The verification method of a `did:key` document.
+///
+/// With the `serde` feature, serializes to the JSON-LD representation used
+/// in DID documents.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationMethod {
+    /// The DID URL of the method, `did:key:<multibase>#<multibase>`.
+    pub id: String,
+    /// The type of the method, `Multikey`.
+    pub type_: String,
+    /// The DID controlling the key, `did:key:<multibase>`.
+    pub controller: String,
+    /// The multibase encoding of the key.
+    pub public_key_multibase: String,
+}
+
+impl From<&PublicKey> for VerificationMethod {
+    fn from(pk: &PublicKey) -> Self {
+        let multibase = pk.to_multibase();
+        let controller = format!("{DID_KEY}{multibase}");
+        Self {
+            id: format!("{controller}#{multibase}"),
+            type_: MULTIKEY.into(),
+            controller,
+            public_key_multibase: multibase,
+        }
+    }
+}
+
+/// Resolve the verification method of a `did:key` identifier, optionally
+/// given as a DID URL with the key fragment.
+///
+/// # Errors
+///
+/// Errors as [`PublicKey::from_did_key`], and with
+/// [`Error::InvalidEncoding`] if the fragment doesn't name the key.
+pub fn resolve_did_key(did: &str) -> Result<VerificationMethod, Error> {
+    let (did, fragment) = match did.split_once('#') {
+        Some((did, fragment)) => (did, Some(fragment)),
+        None => (did, None),
+    };
+    let method = VerificationMethod::from(&PublicKey::from_did_key(did)?);
+    match fragment {
+        Some(fragment) if fragment != method.public_key_multibase => {
+            Err(Error::InvalidEncoding)
+        }
+        _ => Ok(method),
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for VerificationMethod {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut method =
+            serializer.serialize_struct("VerificationMethod", 4)?;
+        method.serialize_field("id", &self.id)?;
+        method.serialize_field("type", &self.type_)?;
+        method.serialize_field("controller", &self.controller)?;
+        method.serialize_field(
+            "publicKeyMultibase",
+            &self.public_key_multibase,
+        )?;
+        method.end()
+    }
+}
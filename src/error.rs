@@ -15,10 +15,53 @@ pub enum Error {
     BytesError(DuskBytesError),
     /// Cryptographic invalidity
     InvalidSignature,
-    /// Invalid Point
+    /// Invalid Point, e.g. not on the curve
     InvalidPoint,
+    /// The point is the identity
+    IdentityPoint,
+    /// The point is not in the prime-order subgroup
+    NotInSubgroup,
+    /// The public key at the given index of an aggregation is invalid
+    InvalidKeyAt {
+        /// Index of the first invalid key
+        index: usize,
+    },
+    /// The public key at the given index of an aggregation already appeared
+    /// earlier in the set
+    DuplicateSigner {
+        /// Index of the first repeated key
+        index: usize,
+    },
     /// Tried to aggregate an empty list of public keys
     NoKeysProvided,
+    /// A freshly produced signature failed to verify against the signer's
+    /// own public key
+    SelfCheckFailed,
+    /// A public key does not belong to the secret key it is used with
+    KeyMismatch,
+    /// The signature scheme is refused by the verification policy
+    SchemeNotAccepted,
+    /// A text or key encoding is malformed, e.g. its checksum doesn't match
+    /// or a required parameter is missing
+    InvalidEncoding,
+    /// A text encoding has the prefix of a different type
+    WrongPrefix,
+    /// A key container holds a key of another type or curve
+    UnsupportedKey,
+    /// A signed message is missing the algorithm of its signature, or names
+    /// another algorithm
+    UnsupportedAlgorithm,
+    /// A mnemonic phrase has a wrong number of words or a bad checksum, or
+    /// its entropy has an invalid size
+    InvalidMnemonic,
+    /// A word of a mnemonic phrase is not in the word list
+    UnknownWord {
+        /// Index of the first unknown word
+        index: usize,
+    },
+    /// A ciphertext is malformed, or was not encrypted to the identity of the
+    /// decryption key
+    DecryptionFailed,
 }
 
 impl From<DuskBytesError> for Error {
@@ -30,20 +73,76 @@ impl From<DuskBytesError> for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::BytesError(err) => write!(f, "{:?}", err),
+            Self::BytesError(DuskBytesError::InvalidData) => {
+                write!(f, "Invalid data")
+            }
+            Self::BytesError(DuskBytesError::BadLength { found, expected }) => {
+                write!(
+                    f,
+                    "Bad length: expected {expected} bytes, found {found}"
+                )
+            }
+            Self::BytesError(DuskBytesError::InvalidChar { ch, index }) => {
+                write!(f, "Invalid character {ch:?} at index {index}")
+            }
             Self::InvalidSignature => {
                 write!(f, "Invalid Signature")
             }
             Self::InvalidPoint => {
                 write!(f, "Invalid Point")
             }
+            Self::IdentityPoint => {
+                write!(f, "Point is the identity")
+            }
+            Self::NotInSubgroup => {
+                write!(f, "Point is not in the prime-order subgroup")
+            }
+            Self::InvalidKeyAt { index } => {
+                write!(f, "Invalid public key at index {index}")
+            }
+            Self::DuplicateSigner { index } => {
+                write!(f, "Duplicate public key at index {index}")
+            }
             Self::NoKeysProvided => {
                 write!(f, "No keys provided")
             }
+            Self::SelfCheckFailed => {
+                write!(f, "Signature self-check failed")
+            }
+            Self::KeyMismatch => {
+                write!(f, "Public key does not match secret key")
+            }
+            Self::SchemeNotAccepted => {
+                write!(f, "Signature scheme not accepted")
+            }
+            Self::InvalidEncoding => {
+                write!(f, "Invalid encoding")
+            }
+            Self::WrongPrefix => {
+                write!(f, "Text encoding has the wrong prefix")
+            }
+            Self::UnsupportedKey => {
+                write!(f, "Unsupported key type or curve")
+            }
+            Self::UnsupportedAlgorithm => {
+                write!(f, "Missing or unsupported signature algorithm")
+            }
+            Self::InvalidMnemonic => {
+                write!(f, "Invalid mnemonic")
+            }
+            Self::UnknownWord { index } => {
+                write!(f, "Unknown mnemonic word at index {index}")
+            }
+            Self::DecryptionFailed => {
+                write!(f, "Decryption failed")
+            }
         }
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
 impl dusk_bytes::BadLength for Error {
     fn bad_length(found: usize, expected: usize) -> Self {
         DuskBytesError::bad_length(found, expected).into()
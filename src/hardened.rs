@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Scalar blinding used by the hardened signing path.
+
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective};
+use sha2::{Digest, Sha256};
+use subtle::{Choice, ConditionallySelectable};
+use zeroize::{Zeroize, Zeroizing};
+
+const BLINDING_DST: &[u8] = b"BLS_HARDENED_BLINDING_SHA-256_DUSK_V1";
+
+// Order of the prime-order subgroups, as little-endian limbs.
+const ORDER: [u64; 4] = [
+    0xffff_ffff_0000_0001,
+    0x53bd_a402_fffe_5bfe,
+    0x3339_d808_09a1_d805,
+    0x73ed_a753_299d_7d48,
+];
+
+// A 255-bit scalar plus a 64-bit multiple of the order fits in 5 limbs.
+const BLINDED_LIMBS: usize = 5;
+
+/// Multiply `point` by `scalar`, blinding the scalar with the multiple `k`
+/// of the group order.
+///
+/// The point is multiplied by the integer `scalar + k * r`, where `r` is the
+/// group order, so the bits driving the ladder change with `k` while the
+/// result stays the same for any point in the prime-order subgroup. `k` is
+/// either drawn at random, or derived with [`message_blinding`].
+pub(crate) fn blinded_mul(
+    point: &G1Affine,
+    scalar: &BlsScalar,
+    k: u64,
+) -> G1Projective {
+    let mut limbs = blind(scalar, k | 1);
+
+    // Double-and-add-always ladder, so that the sequence of group operations
+    // does not depend on the bits of the blinded scalar.
+    let base = G1Projective::from(point);
+    let mut acc = G1Projective::identity();
+    let mut sum = G1Projective::identity();
+    for limb in limbs.iter().rev() {
+        for i in (0..64).rev() {
+            acc = acc.double();
+            sum = acc + base;
+            let bit = Choice::from(((limb >> i) & 1) as u8);
+            acc = G1Projective::conditional_select(&acc, &sum, bit);
+        }
+    }
+
+    limbs.zeroize();
+    sum.zeroize();
+
+    acc
+}
+
+/// Derive the multiple of the group order blinding `scalar` when signing
+/// `msg` without a random number generator.
+///
+/// The blinding differs for every message, like the blinded scalar, but is
+/// the same when signing the same message twice.
+pub(crate) fn message_blinding(scalar: &BlsScalar, msg: &[u8]) -> u64 {
+    let bytes = Zeroizing::new(scalar.to_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(BLINDING_DST);
+    hasher.update(&bytes[..]);
+    hasher.update(msg);
+    let mut digest: [u8; 32] = hasher.finalize().into();
+
+    let mut k = [0u8; 8];
+    k.copy_from_slice(&digest[..8]);
+    digest.zeroize();
+    u64::from_le_bytes(k)
+}
+
+// Compute `scalar + k * r` as a little-endian multi-limb integer.
+fn blind(scalar: &BlsScalar, k: u64) -> [u64; BLINDED_LIMBS] {
+    let mut bytes = scalar.to_bytes();
+    let mut limbs = [0u64; BLINDED_LIMBS];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(buf);
+        buf.zeroize();
+    }
+    bytes.zeroize();
+
+    let mut carry = 0u128;
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let order = ORDER.get(i).copied().unwrap_or(0);
+        let t = *limb as u128 + k as u128 * order as u128 + carry;
+        *limb = t as u64;
+        carry = t >> 64;
+    }
+
+    limbs
+}
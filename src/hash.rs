@@ -8,38 +8,76 @@
 
 use crate::PublicKey;
 
-use dusk_bls12_381::hash_to_curve::{ExpandMsgXmd, HashToCurve};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use core::fmt;
+
+use dusk_bls12_381::hash_to_curve::{
+    ExpandMsgXmd, HashToCurve, HashToField, MapToCurve,
+};
 use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective};
 use dusk_bytes::Serializable;
-use sha2::Sha256;
+use sha2::digest::generic_array::GenericArray;
+use sha2::{Digest, Sha256};
 
 const H0_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_DUSK_V2";
+// Dedicated DST for signing the 32-byte digest of a message.
+const H0_PREHASH_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_DUSK_PREHASH_V2";
+// Dedicated DST for signing typed, domain-separated messages.
+pub(crate) const H0_TYPED_DST: &[u8] =
+    b"BLS_SIG_BLS12381G1_XMD:SHA-256_DUSK_TYPED_V2";
+// Dedicated DST for hashing VRF inputs, keeping VRF proofs apart from
+// signatures.
+pub(crate) const VRF_DST: &[u8] = b"BLS_VRF_BLS12381G1_XMD:SHA-256_DUSK_V1";
 // Dedicated scalar-domain DST for secure multisig coefficients.
 const H1_DST: &[u8] = b"BLS_SIG_BLS12381_SCALAR_SHA256_DUSK_H1_V2";
 
-#[inline]
-fn h0_insecure(msg: &[u8]) -> G1Affine {
-    // Insecure v1 map used by historical blocks/transactions.
-    (G1Affine::generator() * BlsScalar::hash_to_scalar(msg)).into()
+/// Hash a message to a point in G1 under the given domain separation tag.
+///
+/// This is the RFC9380 `hash_to_curve` (random oracle) encoding for the
+/// `BLS12381G1_XMD:SHA-256_SSWU_RO_` suite, and the output is uniformly
+/// distributed in G1.
+pub fn hash_to_g1(msg: &[u8], dst: &[u8]) -> G1Affine {
+    <G1Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(msg, dst)
+        .into()
 }
 
-/// Hash-to-curve-point function for the secure path.
-pub fn h0(msg: &[u8]) -> G1Affine {
-    // RFC9380-style hash-to-curve (random oracle) with explicit DST.
-    <G1Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(
-        msg, H0_DST,
+/// Encode a message to a point in G1 under the given domain separation tag.
+///
+/// This is the RFC9380 `encode_to_curve` (non-uniform) encoding for the
+/// `BLS12381G1_XMD:SHA-256_SSWU_NU_` suite. It is cheaper than
+/// [`hash_to_g1`] but its output is **not** uniformly distributed, so it must
+/// not be used where a random oracle is required, e.g. for signing.
+pub fn encode_to_g1(msg: &[u8], dst: &[u8]) -> G1Affine {
+    <G1Projective as HashToCurve<ExpandMsgXmd<Sha256>>>::encode_to_curve(
+        msg, dst,
     )
     .into()
 }
 
-/// Insecure v1 hash-to-curve-point function.
-pub fn h0_insecure_point(msg: &[u8]) -> G1Affine {
-    h0_insecure(msg)
+/// Hash a message to `count` scalars under the given domain separation tag.
+///
+/// This is the RFC9380 `hash_to_field` for the scalar field of BLS12-381,
+/// using `expand_message_xmd` with SHA-256 and `L = 48` bytes per element.
+pub fn hash_to_scalar(msg: &[u8], dst: &[u8], count: usize) -> Vec<BlsScalar> {
+    let mut scalars = vec![BlsScalar::zero(); count];
+    BlsScalar::hash_to_field::<ExpandMsgXmd<Sha256>>(msg, dst, &mut scalars);
+    scalars
+}
+
+/// Hash-to-curve-point function for the secure path.
+pub fn h0(msg: &[u8]) -> G1Affine {
+    // RFC9380-style hash-to-curve (random oracle) with explicit DST.
+    hash_to_g1(msg, H0_DST)
 }
 
-/// Insecure v1 function used for multisig coefficients.
-pub fn h1_insecure(pk: &PublicKey) -> BlsScalar {
-    BlsScalar::hash_to_scalar(&pk.to_bytes())
+/// Hash-to-curve-point function for 32-byte message digests.
+///
+/// The dedicated DST keeps signatures over a digest apart from signatures
+/// over a 32-byte message.
+pub fn h0_prehashed(digest: &[u8; 32]) -> G1Affine {
+    hash_to_g1(digest, H0_PREHASH_DST)
 }
 
 /// Scalar function used for multisig coefficients on the secure path.
@@ -50,3 +88,110 @@ pub fn h1(pk: &PublicKey) -> BlsScalar {
     material[H1_DST.len()..].copy_from_slice(&pk.to_bytes());
     BlsScalar::hash_to_scalar(&material)
 }
+
+// Field of the curve G1 is defined over.
+type Fp = <G1Projective as MapToCurve>::Field;
+
+// Length of the `expand_message_xmd` output needed for two field elements.
+const UNIFORM_LEN: usize = 2 * 64;
+
+/// Incremental hasher of messages to G1, for signing and verifying messages
+/// without buffering them in memory.
+///
+/// Feeding a message to the hasher, in any number of chunks, produces the
+/// same point as the one used by [`SecretKey::sign`] and [`PublicKey::verify`]
+/// for the whole message, so the resulting [`HashedMessage`] can be used with
+/// [`SecretKey::sign_hashed`] and [`PublicKey::verify_hashed`] interchangeably
+/// with the non-streaming API.
+///
+/// [`SecretKey::sign`]: crate::SecretKey::sign
+/// [`SecretKey::sign_hashed`]: crate::SecretKey::sign_hashed
+/// [`PublicKey::verify_hashed`]: crate::PublicKey::verify_hashed
+#[derive(Clone)]
+pub struct MessageHasher {
+    hasher: Sha256,
+    dst: &'static [u8],
+}
+
+impl Default for MessageHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for MessageHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MessageHasher").finish_non_exhaustive()
+    }
+}
+
+impl MessageHasher {
+    /// Create a new hasher for a message.
+    pub fn new() -> Self {
+        Self::with_dst(H0_DST)
+    }
+
+    /// Create a new hasher for a message under the given DST.
+    pub(crate) fn with_dst(dst: &'static [u8]) -> Self {
+        // `expand_message_xmd` prepends a block of zeroes to the message.
+        let mut hasher = Sha256::new();
+        hasher.update([0u8; 64]);
+        Self { hasher, dst }
+    }
+
+    /// Feed the next chunk of the message to the hasher.
+    pub fn update(&mut self, chunk: impl AsRef<[u8]>) {
+        self.hasher.update(chunk);
+    }
+
+    /// Feed the next chunk of the message to the hasher, returning it.
+    pub fn chain(mut self, chunk: impl AsRef<[u8]>) -> Self {
+        self.update(chunk);
+        self
+    }
+
+    /// Finish hashing the message to a point in G1.
+    pub fn finalize(self) -> HashedMessage {
+        let uniform = expand_message_xmd(self.hasher, self.dst);
+
+        let u0 = Fp::from_okm(GenericArray::from_slice(&uniform[..64]));
+        let u1 = Fp::from_okm(GenericArray::from_slice(&uniform[64..]));
+        let point =
+            G1Projective::map_to_curve(&u0) + G1Projective::map_to_curve(&u1);
+
+        HashedMessage(point.clear_h().into())
+    }
+}
+
+// Finish RFC9380 `expand_message_xmd` for a hasher that has already absorbed
+// `Z_pad || msg`.
+fn expand_message_xmd(mut hasher: Sha256, dst: &[u8]) -> [u8; UNIFORM_LEN] {
+    let dst_len = [dst.len() as u8];
+
+    hasher.update((UNIFORM_LEN as u16).to_be_bytes());
+    hasher.update([0u8]);
+    hasher.update(dst);
+    hasher.update(dst_len);
+    let b_0 = hasher.finalize();
+
+    let mut uniform = [0u8; UNIFORM_LEN];
+    let mut b_i = [0u8; 32];
+    for (i, chunk) in uniform.chunks_exact_mut(32).enumerate() {
+        for (b, b0) in b_i.iter_mut().zip(b_0.iter()) {
+            *b ^= b0;
+        }
+        let mut hasher = Sha256::new();
+        hasher.update(b_i);
+        hasher.update([i as u8 + 1]);
+        hasher.update(dst);
+        hasher.update(dst_len);
+        b_i.copy_from_slice(&hasher.finalize());
+        chunk.copy_from_slice(&b_i);
+    }
+
+    uniform
+}
+
+/// A message hashed to G1 by a [`MessageHasher`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct HashedMessage(pub(crate) G1Affine);
@@ -0,0 +1,249 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Boneh–Franklin identity-based encryption.
+//!
+//! The master public key is a [`PublicKey`], and the decryption key of an
+//! identity is the [`Signature`] on the identity, as produced by
+//! [`SecretKey::sign`](crate::SecretKey::sign). Messages of any length are
+//! encrypted with the `FullIdent` construction, which makes ciphertexts
+//! non-malleable:
+//!
+//! ```text
+//! r = H3(sigma || msg),  U = r * G2
+//! V = sigma ^ H2(e(H(id), pk)^r)
+//! W = msg ^ H4(sigma)
+//! ```
+//!
+//! The hashes and the ciphertext layout are specific to this crate, so
+//! ciphertexts can't be exchanged with other implementations such as tlock.
+//!
+//! Since a drand beacon is a signature on its round, the same construction
+//! encrypts to a future round, see
+//! [`beacon::timelock_encrypt`](crate::beacon::timelock_encrypt).
+//!
+//! Unlike the other schemes of this crate, which add methods to the key
+//! types and are re-exported at the crate root, the scheme is made of free
+//! functions, and names like `encrypt` and `decrypt` would be ambiguous
+//! there, so it is used through the `ibe` module.
+//!
+//! ```
+//! use bls12_381_bls::{PublicKey, SecretKey, ibe};
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(0xbeef);
+//! let master = SecretKey::random(&mut rng);
+//! let master_pk = PublicKey::from(&master);
+//!
+//! let ct = ibe::encrypt(&mut rng, &master_pk, b"alice", b"sealed bid");
+//!
+//! let key = master.sign(b"alice");
+//! assert_eq!(ibe::decrypt(&key, &ct).unwrap(), b"sealed bid");
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use dusk_bls12_381::{BlsScalar, G1Affine, G2Affine, Gt, pairing};
+use dusk_bytes::{BadLength, Serializable};
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+use crate::hash::{h0, hash_to_scalar};
+use crate::keys::public::g2_from_bytes;
+use crate::{Error, PublicKey, Signature};
+
+const H2_DST: &[u8] = b"BLS_IBE_H2_SHA-256_DUSK_V1";
+const H3_DST: &[u8] = b"BLS_IBE_H3_XMD:SHA-256_DUSK_V1";
+const H4_DST: &[u8] = b"BLS_IBE_H4_SHA-256_DUSK_V1";
+
+const SIGMA_SIZE: usize = 32;
+
+/// A message encrypted to an identity.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ciphertext {
+    u: G2Affine,
+    v: [u8; SIGMA_SIZE],
+    w: Vec<u8>,
+}
+
+impl Ciphertext {
+    /// Size of the encoding of a [`Ciphertext`], not counting the message.
+    pub const OVERHEAD: usize = G2Affine::SIZE + SIGMA_SIZE;
+
+    /// Encode the ciphertext as `U || V || W`, with `U` compressed.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::OVERHEAD + self.w.len());
+        bytes.extend_from_slice(&self.u.to_bytes());
+        bytes.extend_from_slice(&self.v);
+        bytes.extend_from_slice(&self.w);
+        bytes
+    }
+
+    /// Decode a ciphertext encoded with [`Ciphertext::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the encoding is shorter than [`Ciphertext::OVERHEAD`], or
+    /// if `U` is not a valid point.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let too_short = || Error::bad_length(bytes.len(), Self::OVERHEAD);
+        let (u, rest) = bytes
+            .split_first_chunk::<{ G2Affine::SIZE }>()
+            .ok_or_else(too_short)?;
+        let (v, w) = rest
+            .split_first_chunk::<SIGMA_SIZE>()
+            .ok_or_else(too_short)?;
+
+        Ok(Self {
+            u: g2_from_bytes(u)?,
+            v: *v,
+            w: w.to_vec(),
+        })
+    }
+}
+
+/// Encrypt `msg` to `identity` under the master public key `master`.
+///
+/// The ciphertext is decrypted with [`decrypt`] and the signature on
+/// `identity` under the master secret key.
+pub fn encrypt<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    master: &PublicKey,
+    identity: &[u8],
+    msg: &[u8],
+) -> Ciphertext {
+    encrypt_to_point(rng, master, &h0(identity), msg)
+}
+
+/// Decrypt a ciphertext with the decryption key of its identity.
+///
+/// # Errors
+///
+/// Errors with [`Error::DecryptionFailed`] if the ciphertext wasn't
+/// encrypted to the identity of `key`, under its master key, or was
+/// tampered with.
+pub fn decrypt(key: &Signature, ct: &Ciphertext) -> Result<Vec<u8>, Error> {
+    let mut sigma = h2(&pairing(&key.0, &ct.u));
+    xor(&mut sigma, &ct.v);
+
+    let mut msg = ct.w.clone();
+    h4_xor(&sigma, &mut msg);
+
+    if G2Affine::from(G2Affine::generator() * h3(&sigma, &msg)) != ct.u {
+        return Err(Error::DecryptionFailed);
+    }
+    Ok(msg)
+}
+
+/// Encrypt `msg` to the identity hashed to `id`.
+pub(crate) fn encrypt_to_point<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    master: &PublicKey,
+    id: &G1Affine,
+    msg: &[u8],
+) -> Ciphertext {
+    let mut sigma = [0u8; SIGMA_SIZE];
+    rng.fill_bytes(&mut sigma);
+
+    let r = h3(&sigma, msg);
+    let u = G2Affine::from(G2Affine::generator() * r);
+
+    let mut v = h2(&pairing(&G1Affine::from(id * r), &master.0));
+    xor(&mut v, &sigma);
+
+    let mut w = msg.to_vec();
+    h4_xor(&sigma, &mut w);
+
+    Ciphertext { u, v, w }
+}
+
+fn xor(dst: &mut [u8], src: &[u8]) {
+    dst.iter_mut().zip(src).for_each(|(d, s)| *d ^= s);
+}
+
+/// Size of the canonical encoding of a `Gt` element.
+const GT_SIZE: usize = 576;
+const FP_SIZE: usize = 48;
+
+/// Encode a `Gt` element canonically, as its twelve `Fp` coefficients from
+/// `c1.c2.c1` down to `c0.c0.c0`, each as 48 big-endian bytes.
+///
+/// `Gt` exposes no encoding, but its `Debug` output writes every coefficient
+/// as `0x` followed by the hex of its canonical bytes, starting with
+/// `c0.c0.c0`, so the coefficients are read back from it. Since that output
+/// is not a stable API, `dusk-bls12_381` is pinned to an exact version, and
+/// the known ciphertext test fails if the encoding ever changes.
+fn gt_to_bytes(gt: &Gt) -> [u8; GT_SIZE] {
+    struct Coefficients {
+        bytes: [u8; GT_SIZE],
+        // Number of coefficients started so far.
+        count: usize,
+        // Hex digits left to read in the current coefficient.
+        digits: usize,
+        prefix: bool,
+    }
+
+    impl fmt::Write for Coefficients {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            for c in s.chars() {
+                if self.digits > 0 {
+                    let digit = c.to_digit(16).ok_or(fmt::Error)? as u8;
+                    let index = 2 * FP_SIZE - self.digits;
+                    let offset = (GT_SIZE / FP_SIZE - self.count) * FP_SIZE;
+                    let shift = if index % 2 == 0 { 4 } else { 0 };
+                    self.bytes[offset + index / 2] |= digit << shift;
+                    self.digits -= 1;
+                } else if self.prefix && c == 'x' {
+                    if self.count == GT_SIZE / FP_SIZE {
+                        return Err(fmt::Error);
+                    }
+                    self.count += 1;
+                    self.digits = 2 * FP_SIZE;
+                    continue;
+                }
+                self.prefix = self.digits == 0 && c == '0';
+            }
+            Ok(())
+        }
+    }
+
+    let mut coefficients = Coefficients {
+        bytes: [0; GT_SIZE],
+        count: 0,
+        digits: 0,
+        prefix: false,
+    };
+    fmt::write(&mut coefficients, format_args!("{gt:?}"))
+        .expect("Gt is formatted as its coefficients");
+    assert_eq!(coefficients.count, GT_SIZE / FP_SIZE);
+    coefficients.bytes
+}
+
+fn h2(gt: &Gt) -> [u8; SIGMA_SIZE] {
+    let mut hasher = Sha256::new();
+    hasher.update(H2_DST);
+    hasher.update(gt_to_bytes(gt));
+    hasher.finalize().into()
+}
+
+fn h3(sigma: &[u8; SIGMA_SIZE], msg: &[u8]) -> BlsScalar {
+    let mut input = Vec::with_capacity(SIGMA_SIZE + msg.len());
+    input.extend_from_slice(sigma);
+    input.extend_from_slice(msg);
+    hash_to_scalar(&input, H3_DST, 1)[0]
+}
+
+fn h4_xor(sigma: &[u8; SIGMA_SIZE], msg: &mut [u8]) {
+    for (i, chunk) in msg.chunks_mut(32).enumerate() {
+        let mut hasher = Sha256::new();
+        hasher.update(H4_DST);
+        hasher.update(sigma);
+        hasher.update((i as u32).to_be_bytes());
+        xor(chunk, &hasher.finalize());
+    }
+}
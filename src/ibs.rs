@@ -0,0 +1,219 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Cha–Cheon identity-based signatures.
+//!
+//! The holder of a master [`SecretKey`] extracts the key of an identity as
+//! the BLS signature on the identity, hashed to G1 under a dedicated DST so
+//! that identity keys can't be mistaken for signatures. Signatures made with
+//! an identity key are verified with the master [`PublicKey`] and the
+//! identity alone:
+//!
+//! ```text
+//! Q = H(id),  d = s * Q
+//! sign:   U = r * Q,  h = H(U || msg),  V = (r + h) * d
+//! verify: e(V, G2) == e(U + h * Q, pk)
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective};
+use dusk_bytes::Serializable;
+use ff::Field;
+use rand_core::{CryptoRng, RngCore};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::hash::{hash_to_g1, hash_to_scalar};
+use crate::keys::public::verify_point;
+use crate::signatures::{check, g1_from_bytes, is_valid};
+use crate::{Error, PublicKey, SecretKey};
+
+const ID_DST: &[u8] = b"BLS_IBS_ID_BLS12381G1_XMD:SHA-256_DUSK_V1";
+const H_DST: &[u8] = b"BLS_IBS_H_XMD:SHA-256_DUSK_V1";
+
+fn h_id(identity: &[u8]) -> G1Affine {
+    hash_to_g1(identity, ID_DST)
+}
+
+fn h(u: &G1Affine, msg: &[u8]) -> BlsScalar {
+    let mut input = Vec::with_capacity(G1Affine::SIZE + msg.len());
+    input.extend_from_slice(&u.to_bytes());
+    input.extend_from_slice(msg);
+    hash_to_scalar(&input, H_DST, 1)[0]
+}
+
+/// The secret key of an identity, extracted from a master [`SecretKey`] with
+/// [`SecretKey::extract_identity_key`].
+///
+/// Like a [`SecretKey`], the key is zeroized on drop, its [`Debug`] output
+/// is redacted, equality is checked in constant time, and it can only be
+/// exported through [`IdentitySecretKey::expose_secret`].
+#[derive(Clone, Eq, Zeroize, ZeroizeOnDrop)]
+pub struct IdentitySecretKey(G1Affine);
+
+impl fmt::Debug for IdentitySecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("IdentitySecretKey(<redacted>)")
+    }
+}
+
+impl ConstantTimeEq for IdentitySecretKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for IdentitySecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl IdentitySecretKey {
+    /// The size of a serialized [`IdentitySecretKey`] in bytes.
+    pub const SIZE: usize = G1Affine::SIZE;
+
+    /// Deserialize an [`IdentitySecretKey`] from its compressed point.
+    ///
+    /// The key is not checked against its identity, see
+    /// [`PublicKey::verify_identity_key`].
+    ///
+    /// # Errors
+    ///
+    /// Errors if the bytes are not a valid point.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        g1_from_bytes(bytes).map(Self)
+    }
+
+    /// Explicitly expose the secret material for export.
+    pub fn expose_secret(&self) -> ExposedIdentitySecretKey<'_> {
+        ExposedIdentitySecretKey(self)
+    }
+
+    /// Sign `msg` on behalf of `identity`, the identity the key was
+    /// extracted for.
+    pub fn sign<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        identity: &[u8],
+        msg: &[u8],
+    ) -> IdentitySignature {
+        let mut r = BlsScalar::random(&mut *rng);
+        let u = G1Affine::from(h_id(identity) * r);
+        let mut k = r + h(&u, msg);
+        let v = G1Affine::from(self.0 * k);
+
+        r.zeroize();
+        k.zeroize();
+        IdentitySignature { u, v }
+    }
+}
+
+/// A borrowed view of an [`IdentitySecretKey`] that allows exporting it.
+///
+/// Obtained through [`IdentitySecretKey::expose_secret`].
+#[derive(Clone, Copy)]
+pub struct ExposedIdentitySecretKey<'a>(&'a IdentitySecretKey);
+
+impl ExposedIdentitySecretKey<'_> {
+    /// Serialize the [`IdentitySecretKey`] into its compressed point.
+    ///
+    /// The caller is responsible for zeroizing the returned bytes.
+    pub fn to_bytes(&self) -> [u8; IdentitySecretKey::SIZE] {
+        self.0.0.to_bytes()
+    }
+}
+
+impl fmt::Debug for ExposedIdentitySecretKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExposedIdentitySecretKey")
+            .field(self.0)
+            .finish()
+    }
+}
+
+/// An identity-based signature, made with an [`IdentitySecretKey`].
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct IdentitySignature {
+    u: G1Affine,
+    v: G1Affine,
+}
+
+impl IdentitySignature {
+    /// Returns true if both inner points are valid, see
+    /// [`Signature::is_valid`](crate::Signature::is_valid).
+    pub fn is_valid(&self) -> bool {
+        is_valid(&self.u) && is_valid(&self.v)
+    }
+}
+
+impl Serializable<96> for IdentitySignature {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[..G1Affine::SIZE].copy_from_slice(&self.u.to_bytes());
+        bytes[G1Affine::SIZE..].copy_from_slice(&self.v.to_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        let (u, v) = bytes
+            .split_first_chunk::<{ G1Affine::SIZE }>()
+            .expect("the encoding holds two points");
+        let v = v.try_into().expect("the encoding holds two points");
+        Ok(Self {
+            u: g1_from_bytes(u)?,
+            v: g1_from_bytes(v)?,
+        })
+    }
+}
+
+impl SecretKey {
+    /// Extract the [`IdentitySecretKey`] of `identity`, with `self` as the
+    /// master key.
+    pub fn extract_identity_key(&self, identity: &[u8]) -> IdentitySecretKey {
+        IdentitySecretKey((h_id(identity) * self.0).into())
+    }
+}
+
+impl PublicKey {
+    /// Verify that `key` is the [`IdentitySecretKey`] of `identity` under
+    /// the master key `self`.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::InvalidSignature`] if the key doesn't belong to
+    /// the identity, or with the point errors of [`PublicKey::verify`].
+    pub fn verify_identity_key(
+        &self,
+        identity: &[u8],
+        key: &IdentitySecretKey,
+    ) -> Result<(), Error> {
+        verify_point(&self.0, &key.0, &h_id(identity))
+    }
+
+    /// Verify a signature made on behalf of `identity`, with `self` as the
+    /// master key.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::InvalidSignature`] if the signature doesn't
+    /// verify, or with the point errors of [`PublicKey::verify`].
+    pub fn verify_identity(
+        &self,
+        identity: &[u8],
+        msg: &[u8],
+        sig: &IdentitySignature,
+    ) -> Result<(), Error> {
+        check(&sig.u)?;
+        let q = h_id(identity);
+        let w = G1Projective::from(sig.u) + q * h(&sig.u, msg);
+        verify_point(&self.0, &sig.v, &w.into())
+    }
+}
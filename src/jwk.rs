@@ -0,0 +1,192 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! JSON Web Key representations of BLS12-381 G2 keys.
+//!
+//! Keys follow the IETF draft for BLS key representations in JOSE and COSE,
+//! with the `BLS12381G2` curve:
+//!
+//! - `OKP` keys carry the compressed public key in `x`.
+//! - `EC2` keys carry the two coordinates of the uncompressed public key in
+//!   `x` and `y`.
+//!
+//! Secret keys additionally carry the big-endian scalar in `d`. All values
+//! are base64url encoded without padding.
+
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use dusk_bytes::{BadLength, Serializable};
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::key_params::{
+    COORDINATE_SIZE, coordinates, from_coordinates, secret_key, with_secret,
+};
+use crate::{Error, ExposedSecretKey, PublicKey, SecretKey};
+
+const CURVE: &str = "BLS12381G2";
+const OKP: &str = "OKP";
+const EC2: &str = "EC2";
+
+/// A JSON Web Key holding a [`PublicKey`], and optionally its [`SecretKey`].
+///
+/// The secret parameter is zeroized on drop and redacted from the [`Debug`]
+/// output.
+///
+/// ```
+/// use bls12_381_bls::{Jwk, PublicKey, SecretKey};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let sk = SecretKey::random(&mut StdRng::seed_from_u64(0xbeef));
+/// let pk = PublicKey::from(&sk);
+///
+/// let json = serde_json::to_string(&pk.to_jwk()).unwrap();
+/// let jwk: Jwk = serde_json::from_str(&json).unwrap();
+/// assert_eq!(PublicKey::from_jwk(&jwk), Ok(pk));
+/// ```
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Jwk {
+    /// The key type, `OKP` or `EC2`.
+    pub kty: String,
+    /// The curve, `BLS12381G2`.
+    pub crv: String,
+    /// The compressed public key for `OKP` keys, the x coordinate of the
+    /// public key for `EC2` keys.
+    pub x: String,
+    /// The y coordinate of the public key for `EC2` keys.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    /// The secret key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub d: Option<String>,
+}
+
+impl fmt::Debug for Jwk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jwk")
+            .field("kty", &self.kty)
+            .field("crv", &self.crv)
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("d", &self.d.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl Drop for Jwk {
+    fn drop(&mut self) {
+        self.d.zeroize();
+    }
+}
+
+impl Jwk {
+    fn okp(pk: &PublicKey) -> Self {
+        Self {
+            kty: OKP.to_string(),
+            crv: CURVE.to_string(),
+            x: URL_SAFE_NO_PAD.encode(pk.to_bytes()),
+            y: None,
+            d: None,
+        }
+    }
+
+    fn ec2(pk: &PublicKey) -> Self {
+        let (x, y) = coordinates(pk);
+        Self {
+            kty: EC2.to_string(),
+            crv: CURVE.to_string(),
+            x: URL_SAFE_NO_PAD.encode(x),
+            y: Some(URL_SAFE_NO_PAD.encode(y)),
+            d: None,
+        }
+    }
+
+    fn with_secret(mut self, sk: &ExposedSecretKey) -> Self {
+        self.d = Some(with_secret(sk, |d| URL_SAFE_NO_PAD.encode(d)));
+        self
+    }
+
+    fn public_key(&self) -> Result<PublicKey, Error> {
+        if self.crv != CURVE {
+            return Err(Error::UnsupportedKey);
+        }
+        match (self.kty.as_str(), &self.y) {
+            (OKP, None) => PublicKey::from_bytes(&decode(&self.x)?),
+            (EC2, Some(y)) => {
+                let x: [u8; COORDINATE_SIZE] = decode(&self.x)?;
+                from_coordinates(&x, &decode(y)?)
+            }
+            (OKP | EC2, _) => Err(Error::InvalidEncoding),
+            _ => Err(Error::UnsupportedKey),
+        }
+    }
+}
+
+fn decode<const N: usize>(s: &str) -> Result<[u8; N], Error> {
+    let bytes = Zeroizing::new(
+        URL_SAFE_NO_PAD
+            .decode(s)
+            .map_err(|_| Error::InvalidEncoding)?,
+    );
+    bytes[..]
+        .try_into()
+        .map_err(|_| Error::bad_length(bytes.len(), N))
+}
+
+impl PublicKey {
+    /// Represent the key as an `OKP` [`Jwk`].
+    pub fn to_jwk(&self) -> Jwk {
+        Jwk::okp(self)
+    }
+
+    /// Represent the key as an `EC2` [`Jwk`].
+    pub fn to_jwk_ec2(&self) -> Jwk {
+        Jwk::ec2(self)
+    }
+
+    /// Read the public key of an `OKP` or `EC2` [`Jwk`].
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::UnsupportedKey`] if the key has another type or
+    /// curve, and with [`Error::InvalidEncoding`] if the parameters of the
+    /// key are missing or not valid base64url.
+    pub fn from_jwk(jwk: &Jwk) -> Result<Self, Error> {
+        jwk.public_key()
+    }
+}
+
+impl ExposedSecretKey<'_> {
+    /// Represent the key, together with its public key, as an `OKP` [`Jwk`].
+    pub fn to_jwk(&self) -> Jwk {
+        Jwk::okp(&PublicKey::from(self.0)).with_secret(self)
+    }
+
+    /// Represent the key, together with its public key, as an `EC2` [`Jwk`].
+    pub fn to_jwk_ec2(&self) -> Jwk {
+        Jwk::ec2(&PublicKey::from(self.0)).with_secret(self)
+    }
+}
+
+impl SecretKey {
+    /// Read the secret key of an `OKP` or `EC2` [`Jwk`].
+    ///
+    /// # Errors
+    ///
+    /// On top of the errors of [`PublicKey::from_jwk`], errors with
+    /// [`Error::InvalidEncoding`] if the key has no secret, and with
+    /// [`Error::KeyMismatch`] if the public key doesn't belong to the secret
+    /// key.
+    pub fn from_jwk(jwk: &Jwk) -> Result<Self, Error> {
+        let pk = jwk.public_key()?;
+        let d = jwk.d.as_ref().ok_or(Error::InvalidEncoding)?;
+        secret_key(&pk, Zeroizing::new(decode(d)?))
+    }
+}
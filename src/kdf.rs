@@ -0,0 +1,42 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! HKDF based derivation of scalars.
+
+use dusk_bls12_381::BlsScalar;
+use hkdf::HkdfExtract;
+use sha2_v010::Sha256;
+use zeroize::Zeroizing;
+
+/// Size of the output keying material reduced to a scalar,
+/// `ceil((3 * ceil(log2(r))) / 16)`.
+pub(crate) const OKM_SIZE: usize = 48;
+
+/// Derive a scalar as `OS2IP(HKDF-Expand(HKDF-Extract(salt, IKM), info, L))
+/// mod r`, where `IKM` and `info` are the concatenations of their parts and
+/// `L` is [`OKM_SIZE`].
+pub(crate) fn hkdf_scalar(
+    salt: &[u8],
+    ikm: &[&[u8]],
+    info: &[&[u8]],
+) -> BlsScalar {
+    let mut extract = HkdfExtract::<Sha256>::new(Some(salt));
+    for part in ikm {
+        extract.input_ikm(part);
+    }
+    let (_, hkdf) = extract.finalize();
+
+    let mut okm = Zeroizing::new([0u8; OKM_SIZE]);
+    hkdf.expand_multi_info(info, &mut okm[..])
+        .expect("48 bytes is a valid HKDF output length");
+
+    // Read the big-endian OKM as a little-endian wide integer.
+    let mut wide = Zeroizing::new([0u8; 64]);
+    for (w, o) in wide.iter_mut().zip(okm.iter().rev()) {
+        *w = *o;
+    }
+    BlsScalar::from_bytes_wide(&wide)
+}
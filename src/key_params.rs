@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Key parameters shared by the JWK and COSE key representations.
+
+use zeroize::Zeroizing;
+
+use crate::{Error, ExposedSecretKey, PublicKey, SecretKey};
+
+/// Size of each coordinate of an uncompressed public key.
+pub(crate) const COORDINATE_SIZE: usize = PublicKey::UNCOMPRESSED_SIZE / 2;
+
+/// Split the uncompressed public key into its `x` and `y` coordinates.
+pub(crate) fn coordinates(
+    pk: &PublicKey,
+) -> ([u8; COORDINATE_SIZE], [u8; COORDINATE_SIZE]) {
+    let bytes = pk.to_uncompressed();
+    let (x, y) = bytes
+        .split_first_chunk::<COORDINATE_SIZE>()
+        .expect("the encoding holds two coordinates");
+    (
+        *x,
+        y.try_into().expect("the encoding holds two coordinates"),
+    )
+}
+
+/// Read a public key from its `x` and `y` coordinates.
+pub(crate) fn from_coordinates(
+    x: &[u8; COORDINATE_SIZE],
+    y: &[u8; COORDINATE_SIZE],
+) -> Result<PublicKey, Error> {
+    let mut bytes = [0u8; PublicKey::UNCOMPRESSED_SIZE];
+    bytes[..COORDINATE_SIZE].copy_from_slice(x);
+    bytes[COORDINATE_SIZE..].copy_from_slice(y);
+    PublicKey::from_uncompressed(&bytes)
+}
+
+/// Hand the big-endian secret scalar to `f`, zeroizing it afterwards.
+pub(crate) fn with_secret<T>(
+    sk: &ExposedSecretKey,
+    f: impl FnOnce(&[u8]) -> T,
+) -> T {
+    let mut bytes = Zeroizing::new(sk.to_bytes());
+    bytes.reverse();
+    f(&bytes[..])
+}
+
+/// Read a secret key from its big-endian scalar, and check that it belongs
+/// to `pk`.
+pub(crate) fn secret_key(
+    pk: &PublicKey,
+    mut bytes: Zeroizing<[u8; SecretKey::SIZE]>,
+) -> Result<SecretKey, Error> {
+    bytes.reverse();
+    let sk = SecretKey::from_bytes(&bytes)?;
+
+    if PublicKey::from(&sk) != *pk {
+        return Err(Error::KeyMismatch);
+    }
+    Ok(sk)
+}
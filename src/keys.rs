@@ -4,5 +4,6 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
+pub mod keypair;
 pub mod public;
 pub mod secret;
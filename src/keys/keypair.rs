@@ -0,0 +1,185 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use crate::hash::{h0, h1};
+use crate::{Error, MultisigSignature, PublicKey, SecretKey, Signature};
+
+use core::fmt;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+use rand_core::{CryptoRng, RngCore};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A BLS keypair, binding a [`SecretKey`] to its [`PublicKey`].
+///
+/// The keypair caches the public key and its multisig coefficient, so signing
+/// multisig messages neither recomputes them nor relies on the caller to pass
+/// the matching [`PublicKey`].
+///
+/// Like the [`SecretKey`] it holds, the keypair is zeroized on drop and can
+/// only be exported through [`Keypair::expose_secret`].
+#[derive(Clone, Debug, Eq, PartialEq, Zeroize, ZeroizeOnDrop)]
+pub struct Keypair {
+    sk: SecretKey,
+    #[zeroize(skip)]
+    pk: PublicKey,
+    t: BlsScalar,
+}
+
+impl From<SecretKey> for Keypair {
+    /// Generates a new [`Keypair`] from a [`SecretKey`].
+    fn from(sk: SecretKey) -> Self {
+        let pk = PublicKey::from(&sk);
+        let t = h1(&pk);
+        Self { sk, pk, t }
+    }
+}
+
+impl From<&SecretKey> for Keypair {
+    /// Generates a new [`Keypair`] from a [`SecretKey`].
+    fn from(sk: &SecretKey) -> Self {
+        Self::from(sk.clone())
+    }
+}
+
+impl Keypair {
+    /// The size of a serialized [`Keypair`] in bytes.
+    pub const SIZE: usize = SecretKey::SIZE + PublicKey::SIZE;
+
+    /// Generates a new random [`Keypair`].
+    pub fn random<T>(rand: &mut T) -> Self
+    where
+        T: RngCore + CryptoRng,
+    {
+        Self::from(SecretKey::random(rand))
+    }
+
+    /// Returns the [`SecretKey`] of the keypair.
+    pub fn secret_key(&self) -> &SecretKey {
+        &self.sk
+    }
+
+    /// Returns the [`PublicKey`] of the keypair.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.pk
+    }
+
+    /// Sign a message using the default single-signature behavior.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        self.sk.sign(msg)
+    }
+
+    /// Sign a message using the default multi-signature behavior.
+    ///
+    /// The signature is identical to
+    /// [`SecretKey::sign_multisig`] called with the keypair's own
+    /// [`PublicKey`].
+    pub fn sign_multisig(&self, msg: &[u8]) -> MultisigSignature {
+        let mut sk_t = self.sk.0 * self.t;
+        let sig = MultisigSignature((h0(msg) * sk_t).into());
+        sk_t.zeroize();
+        sig
+    }
+
+    /// Sign a message using the hardened single-signature path, checking
+    /// the signature against the cached [`PublicKey`].
+    ///
+    /// See [`SecretKey::sign_hardened`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SelfCheckFailed`] if the signature does not verify.
+    #[cfg(feature = "hardened")]
+    pub fn sign_hardened<R>(
+        &self,
+        rng: &mut R,
+        msg: &[u8],
+    ) -> Result<Signature, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        self.sk.sign_hardened(rng, &self.pk, msg)
+    }
+
+    /// Sign a message using the hardened multi-signature path, checking
+    /// the signature against the cached [`PublicKey`].
+    ///
+    /// See [`SecretKey::sign_multisig_hardened`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SelfCheckFailed`] if the signature does not verify.
+    #[cfg(feature = "hardened")]
+    pub fn sign_multisig_hardened<R>(
+        &self,
+        rng: &mut R,
+        msg: &[u8],
+    ) -> Result<MultisigSignature, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        self.sk.sign_multisig_hardened(rng, &self.pk, msg)
+    }
+
+    /// Deserialize a [`Keypair`] from the concatenation of its [`SecretKey`]
+    /// and [`PublicKey`] bytes.
+    ///
+    /// # Errors
+    ///
+    /// Errors if either key fails to deserialize, or with
+    /// [`Error::KeyMismatch`] if the public key does not belong to the secret
+    /// key.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        let mut sk_bytes = [0u8; SecretKey::SIZE];
+        sk_bytes.copy_from_slice(&bytes[..SecretKey::SIZE]);
+        let sk = SecretKey::from_bytes(&sk_bytes);
+        sk_bytes.zeroize();
+
+        let mut pk_bytes = [0u8; PublicKey::SIZE];
+        pk_bytes.copy_from_slice(&bytes[SecretKey::SIZE..]);
+        let pk = PublicKey::from_bytes(&pk_bytes)?;
+
+        let keypair = Self::from(sk?);
+        if keypair.pk != pk {
+            return Err(Error::KeyMismatch);
+        }
+        Ok(keypair)
+    }
+
+    /// Explicitly expose the secret material for export.
+    ///
+    /// See [`SecretKey::expose_secret`].
+    pub fn expose_secret(&self) -> ExposedKeypair<'_> {
+        ExposedKeypair(self)
+    }
+}
+
+/// A borrowed view of a [`Keypair`] that allows exporting it.
+///
+/// Obtained through [`Keypair::expose_secret`].
+#[derive(Clone, Copy)]
+pub struct ExposedKeypair<'a>(pub(crate) &'a Keypair);
+
+impl ExposedKeypair<'_> {
+    /// Serialize the [`Keypair`] into the concatenation of its [`SecretKey`]
+    /// and [`PublicKey`] bytes.
+    ///
+    /// The caller is responsible for zeroizing the returned bytes.
+    pub fn to_bytes(&self) -> [u8; Keypair::SIZE] {
+        let mut bytes = [0u8; Keypair::SIZE];
+        bytes[..SecretKey::SIZE]
+            .copy_from_slice(&self.0.sk.expose_secret().to_bytes());
+        bytes[SecretKey::SIZE..].copy_from_slice(&self.0.pk.to_bytes());
+        bytes
+    }
+}
+
+impl fmt::Debug for ExposedKeypair<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExposedKeypair").field(self.0).finish()
+    }
+}
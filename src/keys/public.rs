@@ -4,12 +4,17 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::hash::{h0, h0_insecure_point, h1, h1_insecure};
-use crate::signatures::is_valid as is_valid_sig;
+use crate::batch::decode_batch;
+use crate::hash::{HashedMessage, h0, h0_prehashed, h1};
+use crate::signatures::{check as check_sig, decompression_error};
 use crate::{Error, MultisigSignature, SecretKey, Signature};
 
 use dusk_bls12_381::{G1Affine, G2Affine, G2Prepared, G2Projective, Gt};
 use dusk_bytes::{Error as DuskBytesError, Serializable};
+use sha2::{Digest, Sha256};
+
+use alloc::vec::Vec;
+use core::fmt;
 
 #[cfg(feature = "rkyv-impl")]
 use rkyv::{Archive, Deserialize, Serialize};
@@ -27,17 +32,17 @@ use rayon::prelude::*;
     derive(Archive, Deserialize, Serialize),
     archive_attr(derive(bytecheck::CheckBytes))
 )]
-pub struct PublicKey(G2Affine);
+pub struct PublicKey(pub(crate) G2Affine);
 
 impl Serializable<96> for PublicKey {
-    type Error = DuskBytesError;
+    type Error = Error;
 
     fn to_bytes(&self) -> [u8; Self::SIZE] {
         self.0.to_bytes()
     }
 
     fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
-        Ok(Self(G2Affine::from_bytes(bytes)?))
+        g2_from_bytes(bytes).map(Self)
     }
 }
 
@@ -58,19 +63,24 @@ impl PublicKey {
         verify_signature(&self.0, &sig.0, msg)
     }
 
-    /// Verify a [`Signature`] using the insecure v1 behavior.
-    ///
-    /// This path exists only for historical compatibility with signatures
-    /// produced before the secure hash-to-curve migration. It uses the legacy
-    /// v1 mapping and should not be used for new signatures.
-    ///
-    /// For all new operations, use [`PublicKey::verify`].
-    pub fn verify_insecure(
+    /// Verify a [`Signature`] over a message hashed with a
+    /// [`MessageHasher`](crate::MessageHasher).
+    pub fn verify_hashed(
         &self,
         sig: &Signature,
-        msg: &[u8],
+        hashed: &HashedMessage,
+    ) -> Result<(), Error> {
+        verify_point(&self.0, &sig.0, &hashed.0)
+    }
+
+    /// Verify a [`Signature`] over the 32-byte digest of a message, as
+    /// produced by [`SecretKey::sign_prehashed`].
+    pub fn verify_prehashed(
+        &self,
+        sig: &Signature,
+        digest: &[u8; 32],
     ) -> Result<(), Error> {
-        verify_insecure_signature(&self.0, &sig.0, msg)
+        verify_point(&self.0, &sig.0, &h0_prehashed(digest))
     }
 
     /// Return pk * t, where t is H_(pk).
@@ -80,16 +90,6 @@ impl PublicKey {
         gx.into()
     }
 
-    /// Return `pk * t` for the insecure v1 multisig construction.
-    ///
-    /// Here `t` is computed with the legacy insecure coefficient hash and is
-    /// only valid for historical multisig verification.
-    pub fn pk_t_insecure(&self) -> G2Affine {
-        let t = h1_insecure(self);
-        let gx = self.0 * t;
-        gx.into()
-    }
-
     /// Raw bytes representation
     ///
     /// The intended usage of this function is for trusted sets of data where
@@ -114,6 +114,11 @@ impl PublicKey {
         unsafe { Self(G2Affine::from_slice_unchecked(bytes)) }
     }
 
+    /// Returns a short identifier of the key, meant for logs.
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::new(b"blspk", &self.to_bytes())
+    }
+
     /// Returns true if the inner point is valid according to certain criteria.
     ///
     /// A [`PublicKey`] is considered valid if its inner point meets the
@@ -125,17 +130,63 @@ impl PublicKey {
     pub fn is_valid(&self) -> bool {
         is_valid(&self.0)
     }
+
+    /// Size of the uncompressed encoding, see [`PublicKey::to_uncompressed`].
+    pub const UNCOMPRESSED_SIZE: usize = 192;
+
+    /// Serialize the key as an uncompressed G2 point, in the ZCash
+    /// serialization format.
+    pub fn to_uncompressed(&self) -> [u8; Self::UNCOMPRESSED_SIZE] {
+        self.0.to_uncompressed()
+    }
+
+    /// Deserialize a key from its uncompressed encoding.
+    ///
+    /// Decoding avoids the square root needed by [`PublicKey::from_bytes`],
+    /// which makes it the faster option for ingesting large sets of keys,
+    /// while still fully validating the point.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the encoding is malformed, or the point is the identity,
+    /// not on the curve, or outside of the prime-order subgroup.
+    pub fn from_uncompressed(
+        bytes: &[u8; Self::UNCOMPRESSED_SIZE],
+    ) -> Result<Self, Error> {
+        g2_from_uncompressed(bytes).map(Self)
+    }
+
+    /// Deserialize a list of [`PublicKey`]s, as produced by `to_bytes`.
+    ///
+    /// Each encoding is decompressed and checked on its own like with
+    /// `from_bytes`, in parallel when the `parallel` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns the index of the first encoding that fails to deserialize,
+    /// together with its error.
+    pub fn from_bytes_batch(
+        bytes: &[[u8; 96]],
+    ) -> Result<Vec<Self>, (usize, Error)> {
+        decode_batch(bytes, Self::from_bytes)
+    }
 }
 
-fn verify_insecure_signature(
+fn verify_signature(
     key: &G2Affine,
     sig: &G1Affine,
     msg: &[u8],
 ) -> Result<(), Error> {
-    if !is_valid(key) || !is_valid_sig(sig) {
-        return Err(Error::InvalidPoint);
-    }
-    let h0m = h0_insecure_point(msg);
+    verify_point(key, sig, &h0(msg))
+}
+
+pub(crate) fn verify_point(
+    key: &G2Affine,
+    sig: &G1Affine,
+    h0m: &G1Affine,
+) -> Result<(), Error> {
+    check(key)?;
+    check_sig(sig)?;
     // e(sig, g2) == e(H(msg), pk) rewritten as
     // e(sig, g2) * e(-H(msg), pk) == 1 in one multi-miller loop.
     let p = dusk_bls12_381::multi_miller_loop(&[
@@ -151,33 +202,65 @@ fn verify_insecure_signature(
     }
 }
 
-fn verify_signature(
-    key: &G2Affine,
-    sig: &G1Affine,
-    msg: &[u8],
-) -> Result<(), Error> {
-    if !is_valid(key) || !is_valid_sig(sig) {
+pub(crate) fn is_valid(key: &G2Affine) -> bool {
+    check(key).is_ok()
+}
+
+/// Decode a compressed G2 point, reporting why it is invalid.
+///
+/// Like the decoding of `G2Affine`, the identity is accepted.
+pub(crate) fn g2_from_bytes(bytes: &[u8; 96]) -> Result<G2Affine, Error> {
+    let point: G2Affine =
+        Option::from(G2Affine::from_compressed_unchecked(bytes))
+            .ok_or_else(|| decompression_error(bytes[0]))?;
+    if !bool::from(point.is_torsion_free()) {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(point)
+}
+
+/// Decode an uncompressed G2 point and check it is a valid key.
+fn g2_from_uncompressed(bytes: &[u8; 192]) -> Result<G2Affine, Error> {
+    let point = Option::from(G2Affine::from_uncompressed_unchecked(bytes))
+        .ok_or(DuskBytesError::InvalidData)?;
+    check(&point)?;
+    Ok(point)
+}
+
+/// Check a public key point, reporting why it is invalid.
+pub(crate) fn check(key: &G2Affine) -> Result<(), Error> {
+    if !bool::from(key.is_on_curve()) {
         return Err(Error::InvalidPoint);
     }
-    let h0m = h0(msg);
-    // e(sig, g2) == e(H(msg), pk) rewritten as
-    // e(sig, g2) * e(-H(msg), pk) == 1 in one multi-miller loop.
-    let p = dusk_bls12_381::multi_miller_loop(&[
-        (sig, &G2Prepared::from(G2Affine::generator())),
-        (&-h0m, &G2Prepared::from(*key)),
-    ])
-    .final_exponentiation();
+    if key.is_identity().into() {
+        return Err(Error::IdentityPoint);
+    }
+    if !bool::from(key.is_torsion_free()) {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(())
+}
 
-    if p.eq(&Gt::identity()) {
-        Ok(())
-    } else {
-        Err(Error::InvalidSignature)
+/// A short identifier of a public key, meant for logs.
+///
+/// It consists of the first 8 bytes of the SHA-256 hash of the key's type
+/// prefix followed by its compressed bytes, and displays as hex.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fingerprint(pub [u8; 8]);
+
+impl Fingerprint {
+    fn new(prefix: &[u8], bytes: &[u8]) -> Self {
+        let digest = Sha256::new().chain(prefix).chain(bytes).finalize();
+        let mut fingerprint = [0u8; 8];
+        fingerprint.copy_from_slice(&digest[..8]);
+        Self(fingerprint)
     }
 }
 
-fn is_valid(key: &G2Affine) -> bool {
-    let is_identity: bool = key.is_identity().into();
-    key.is_torsion_free().into() && key.is_on_curve().into() && !is_identity
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
 }
 
 /// Aggregated form of a BLS public key.
@@ -190,17 +273,17 @@ fn is_valid(key: &G2Affine) -> bool {
     derive(Archive, Deserialize, Serialize),
     archive_attr(derive(bytecheck::CheckBytes))
 )]
-pub struct MultisigPublicKey(G2Affine);
+pub struct MultisigPublicKey(pub(crate) G2Affine);
 
 impl Serializable<96> for MultisigPublicKey {
-    type Error = DuskBytesError;
+    type Error = Error;
 
     fn to_bytes(&self) -> [u8; Self::SIZE] {
         self.0.to_bytes()
     }
 
     fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
-        Ok(MultisigPublicKey(G2Affine::from_bytes(bytes)?))
+        g2_from_bytes(bytes).map(MultisigPublicKey)
     }
 }
 
@@ -209,83 +292,35 @@ impl MultisigPublicKey {
     ///
     /// # Errors
     ///
-    /// The aggregation errors when an empty slice is passed, or one of the
+    /// The aggregation errors with [`Error::NoKeysProvided`] when an empty
+    /// slice is passed, and with [`Error::InvalidKeyAt`] when one of the
     /// [`PublicKey`]s is made of the identity or an otherwise invalid point.
+    ///
+    /// A [`PublicKey`] appearing more than once is counted once per
+    /// appearance, see [`MultisigPublicKey::aggregate_distinct`] to reject
+    /// it instead.
     pub fn aggregate(pks: &[PublicKey]) -> Result<Self, Error> {
-        if pks.is_empty() {
-            return Err(Error::NoKeysProvided);
-        }
-
-        #[cfg(not(feature = "parallel"))]
-        let valid_iter = pks.iter();
-        #[cfg(feature = "parallel")]
-        let valid_iter = pks.par_iter();
-
-        #[cfg(not(feature = "parallel"))]
-        let pks_valid =
-            valid_iter.fold(true, |acc, next| acc & next.is_valid());
-        #[cfg(feature = "parallel")]
-        let pks_valid = valid_iter
-            .map(PublicKey::is_valid)
-            .reduce(|| true, |acc, next| acc & next);
-
-        if !pks_valid {
-            return Err(Error::InvalidPoint);
-        }
-
-        #[cfg(not(feature = "parallel"))]
-        let sum_iter = pks.iter();
-        #[cfg(feature = "parallel")]
-        let sum_iter = pks.par_iter();
-
-        let sum: G2Projective =
-            sum_iter.map(|pk| G2Projective::from(pk.pk_t())).sum();
-
-        Ok(Self(sum.into()))
+        aggregate_keys(pks, |pk| pk.0, PublicKey::pk_t, false).map(Self)
     }
 
-    /// Aggregate keys using insecure v1 multisig coefficients.
+    /// Aggregate a set of distinct [`PublicKey`]s into a
+    /// [`MultisigPublicKey`].
     ///
-    /// This exists only for verifying historical multisignatures created with
-    /// the legacy v1 coefficient hash. New aggregations should use
-    /// [`MultisigPublicKey::aggregate`].
+    /// Checking for duplicates sorts a copy of the keys, so this is more
+    /// expensive than [`MultisigPublicKey::aggregate`].
     ///
     /// # Errors
     ///
-    /// The aggregation errors when an empty slice is passed, or one of the
-    /// [`PublicKey`]s is made of the identity or an otherwise invalid point.
-    pub fn aggregate_insecure(pks: &[PublicKey]) -> Result<Self, Error> {
-        if pks.is_empty() {
-            return Err(Error::NoKeysProvided);
-        }
-
-        #[cfg(not(feature = "parallel"))]
-        let valid_iter = pks.iter();
-        #[cfg(feature = "parallel")]
-        let valid_iter = pks.par_iter();
-
-        #[cfg(not(feature = "parallel"))]
-        let pks_valid =
-            valid_iter.fold(true, |acc, next| acc & next.is_valid());
-        #[cfg(feature = "parallel")]
-        let pks_valid = valid_iter
-            .map(PublicKey::is_valid)
-            .reduce(|| true, |acc, next| acc & next);
-
-        if !pks_valid {
-            return Err(Error::InvalidPoint);
-        }
-
-        #[cfg(not(feature = "parallel"))]
-        let sum_iter = pks.iter();
-        #[cfg(feature = "parallel")]
-        let sum_iter = pks.par_iter();
-
-        let sum: G2Projective = sum_iter
-            .map(|pk| G2Projective::from(pk.pk_t_insecure()))
-            .sum();
+    /// On top of the errors of [`MultisigPublicKey::aggregate`], errors with
+    /// [`Error::DuplicateSigner`] when a [`PublicKey`] appears more than
+    /// once.
+    pub fn aggregate_distinct(pks: &[PublicKey]) -> Result<Self, Error> {
+        aggregate_keys(pks, |pk| pk.0, PublicKey::pk_t, true).map(Self)
+    }
 
-        Ok(Self(sum.into()))
+    /// Returns a short identifier of the key, meant for logs.
+    pub fn fingerprint(&self) -> Fingerprint {
+        Fingerprint::new(b"blsapk", &self.to_bytes())
     }
 
     /// Verify a [`MultisigSignature`].
@@ -300,19 +335,6 @@ impl MultisigPublicKey {
         verify_signature(&self.0, &sig.0, msg)
     }
 
-    /// Verify a [`MultisigSignature`] using the insecure v1 behavior.
-    ///
-    /// This path exists only for historical compatibility with multisignatures
-    /// produced before the secure hash-to-curve migration. For all new
-    /// operations, use [`MultisigPublicKey::verify`].
-    pub fn verify_insecure(
-        &self,
-        sig: &MultisigSignature,
-        msg: &[u8],
-    ) -> Result<(), Error> {
-        verify_insecure_signature(&self.0, &sig.0, msg)
-    }
-
     /// Raw bytes representation
     ///
     /// The intended usage of this function is for trusted sets of data where
@@ -336,4 +358,85 @@ impl MultisigPublicKey {
     pub unsafe fn from_slice_unchecked(bytes: &[u8]) -> Self {
         unsafe { MultisigPublicKey(G2Affine::from_slice_unchecked(bytes)) }
     }
+
+    /// Size of the uncompressed encoding, see
+    /// [`MultisigPublicKey::to_uncompressed`].
+    pub const UNCOMPRESSED_SIZE: usize = 192;
+
+    /// Serialize the key as an uncompressed G2 point, in the ZCash
+    /// serialization format.
+    pub fn to_uncompressed(&self) -> [u8; Self::UNCOMPRESSED_SIZE] {
+        self.0.to_uncompressed()
+    }
+
+    /// Deserialize a key from its uncompressed encoding.
+    ///
+    /// Decoding avoids the square root needed by
+    /// [`MultisigPublicKey::from_bytes`], which makes it the faster option
+    /// for ingesting large sets of keys, while still fully validating the
+    /// point.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the encoding is malformed, or the point is the identity,
+    /// not on the curve, or outside of the prime-order subgroup.
+    pub fn from_uncompressed(
+        bytes: &[u8; Self::UNCOMPRESSED_SIZE],
+    ) -> Result<Self, Error> {
+        g2_from_uncompressed(bytes).map(Self)
+    }
+}
+
+/// Sum up `pk * t` for a set of public keys, after checking their validity
+/// and, if `distinct` is set, that no key appears twice.
+pub(crate) fn aggregate_keys<K: Sync>(
+    pks: &[K],
+    point: fn(&K) -> G2Affine,
+    pk_t: fn(&K) -> G2Affine,
+    distinct: bool,
+) -> Result<G2Affine, Error> {
+    if pks.is_empty() {
+        return Err(Error::NoKeysProvided);
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    let invalid = pks.iter().position(|pk| !is_valid(&point(pk)));
+    #[cfg(feature = "parallel")]
+    let invalid = pks.par_iter().position_first(|pk| !is_valid(&point(pk)));
+
+    if let Some(index) = invalid {
+        return Err(Error::InvalidKeyAt { index });
+    }
+
+    if distinct {
+        if let Some(index) = first_duplicate(pks, point) {
+            return Err(Error::DuplicateSigner { index });
+        }
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    let sum_iter = pks.iter();
+    #[cfg(feature = "parallel")]
+    let sum_iter = pks.par_iter();
+
+    let sum: G2Projective =
+        sum_iter.map(|pk| G2Projective::from(pk_t(pk))).sum();
+
+    Ok(sum.into())
+}
+
+/// Returns the index of the first key that already appeared earlier in `pks`.
+fn first_duplicate<K>(pks: &[K], point: fn(&K) -> G2Affine) -> Option<usize> {
+    let mut sorted: Vec<_> = pks
+        .iter()
+        .enumerate()
+        .map(|(index, pk)| (point(pk).to_raw_bytes(), index))
+        .collect();
+    sorted.sort_unstable();
+
+    sorted
+        .windows(2)
+        .filter(|pair| pair[0].0 == pair[1].0)
+        .map(|pair| pair[1].1)
+        .min()
 }
@@ -4,28 +4,32 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use crate::hash::{h0, h1};
-#[cfg(feature = "insecure-v1-signing")]
-use crate::hash::{h0_insecure_point, h1_insecure};
-use crate::{MultisigSignature, PublicKey, Signature};
+#[cfg(feature = "hardened")]
+use crate::MultisigPublicKey;
+#[cfg(feature = "hardened")]
+use crate::hardened::{blinded_mul, message_blinding};
+use crate::hash::{HashedMessage, h0, h0_prehashed, h1};
+use crate::{Error, MultisigSignature, PublicKey, Signature};
+
+use core::fmt;
 
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::{Error as DuskBytesError, Serializable};
 use ff::Field;
 use rand_core::{CryptoRng, RngCore};
-use zeroize::Zeroize;
-
-#[cfg(feature = "rkyv-impl")]
-use rkyv::{Archive, Deserialize, Serialize};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// A BLS secret key, holding a BLS12-381 scalar inside.
 /// Can be used for signing messages.
 ///
 /// ## Safety
 ///
-/// To ensure that no secret information lingers in memory after the variable
-/// goes out of scope, we advice calling `zeroize` before the variable goes out
-/// of scope.
+/// The inner scalar is zeroized when the key goes out of scope, its [`Debug`]
+/// output is redacted and equality is checked in constant time.
+///
+/// The key can only be exported through the explicit
+/// [`SecretKey::expose_secret`] accessor.
 ///
 /// ## Examples
 ///
@@ -34,21 +38,16 @@ use rkyv::{Archive, Deserialize, Serialize};
 /// use bls12_381_bls::SecretKey;
 /// use rand::rngs::StdRng;
 /// use rand::SeedableRng;
-/// use zeroize::Zeroize;
 ///
 /// let mut rng = StdRng::seed_from_u64(12345);
-/// let mut sk = SecretKey::random(&mut rng);
+/// let sk = SecretKey::random(&mut rng);
 ///
 /// // do something with the sk
 ///
-/// sk.zeroize();
+/// let bytes = sk.expose_secret().to_bytes();
+/// assert_eq!(sk, SecretKey::from_bytes(&bytes).unwrap());
 /// ```
-#[derive(Default, Clone, Debug, Eq, PartialEq, Zeroize)]
-#[cfg_attr(
-    feature = "rkyv-impl",
-    derive(Archive, Deserialize, Serialize),
-    archive_attr(derive(bytecheck::CheckBytes))
-)]
+#[derive(Default, Clone, Eq, Zeroize, ZeroizeOnDrop)]
 pub struct SecretKey(pub(crate) BlsScalar);
 
 impl From<BlsScalar> for SecretKey {
@@ -63,13 +62,28 @@ impl From<&BlsScalar> for SecretKey {
     }
 }
 
-impl AsRef<BlsScalar> for SecretKey {
-    fn as_ref(&self) -> &BlsScalar {
-        &self.0
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey(<redacted>)")
+    }
+}
+
+impl ConstantTimeEq for SecretKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
     }
 }
 
 impl SecretKey {
+    /// The size of a serialized [`SecretKey`] in bytes.
+    pub const SIZE: usize = BlsScalar::SIZE;
+
     /// Generates a new random [`SecretKey`] from a [`BlsScalar].
     pub fn random<T>(rand: &mut T) -> Self
     where
@@ -77,82 +91,240 @@ impl SecretKey {
     {
         Self(BlsScalar::random(&mut *rand))
     }
-}
-
-impl Serializable<32> for SecretKey {
-    type Error = DuskBytesError;
 
-    fn to_bytes(&self) -> [u8; Self::SIZE] {
-        self.0.to_bytes()
-    }
-
-    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+    /// Deserialize a [`SecretKey`] from its canonical byte representation.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the bytes are not a canonical encoding of a scalar.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
         let secret_key = match BlsScalar::from_bytes(bytes).into() {
             Some(sk) => sk,
-            None => return Err(DuskBytesError::InvalidData),
+            None => return Err(DuskBytesError::InvalidData.into()),
         };
         Ok(Self(secret_key))
     }
+
+    /// Explicitly expose the secret material for export.
+    ///
+    /// Every serialization of a [`SecretKey`] has to go through the returned
+    /// [`ExposedSecretKey`], so that exports are easy to spot in review.
+    pub fn expose_secret(&self) -> ExposedSecretKey<'_> {
+        ExposedSecretKey(self)
+    }
+}
+
+/// A borrowed view of a [`SecretKey`] that allows exporting it.
+///
+/// Obtained through [`SecretKey::expose_secret`].
+#[derive(Clone, Copy)]
+pub struct ExposedSecretKey<'a>(pub(crate) &'a SecretKey);
+
+impl ExposedSecretKey<'_> {
+    /// Serialize the [`SecretKey`] into its canonical byte representation.
+    ///
+    /// The caller is responsible for zeroizing the returned bytes.
+    pub fn to_bytes(&self) -> [u8; SecretKey::SIZE] {
+        self.0.0.to_bytes()
+    }
+}
+
+impl fmt::Debug for ExposedSecretKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExposedSecretKey").field(self.0).finish()
+    }
 }
 
 impl SecretKey {
     /// Sign a message using the default single-signature behavior.
+    ///
+    /// With the `hardened` feature, the scalar is blinded, the intermediate
+    /// points are zeroized and the signature is checked before it is
+    /// returned, like with `SecretKey::sign_hardened`. Without a random
+    /// number generator, the scalar is blinded with a multiple of the group
+    /// order derived from the key and the message, and the signature is
+    /// checked against the public key derived from `self`, which catches
+    /// faults injected while signing but not a scalar corrupted in memory.
+    ///
+    /// # Panics
+    ///
+    /// With the `hardened` feature, panics if the self-check fails instead of
+    /// returning a faulty signature. `SecretKey::sign_hardened` returns an
+    /// error instead.
     pub fn sign(&self, msg: &[u8]) -> Signature {
         // Hash message
         let h = h0(msg);
 
+        #[cfg(feature = "hardened")]
+        {
+            let k = message_blinding(&self.0, msg);
+            let sig = Signature(blinded_mul(&h, &self.0, k).into());
+            PublicKey::from(self)
+                .verify(&sig, msg)
+                .expect("hardened signature self-check failed");
+            sig
+        }
+
         // Multiply point by sk
-        let e = h * self.0;
-        Signature(e.into())
+        #[cfg(not(feature = "hardened"))]
+        Signature((h * self.0).into())
     }
 
-    /// Sign a message using the insecure v1 single-signature scheme.
+    /// Sign a message hashed with a [`MessageHasher`].
     ///
-    /// This path is considered insecure and is intentionally gated behind
-    /// the `insecure-v1-signing` feature.
-    #[cfg(feature = "insecure-v1-signing")]
-    pub fn sign_insecure(&self, msg: &[u8]) -> Signature {
-        // Hash message
-        let h = h0_insecure_point(msg);
+    /// The signature is identical to the one [`SecretKey::sign`] produces
+    /// for the whole message.
+    ///
+    /// [`MessageHasher`]: crate::MessageHasher
+    pub fn sign_hashed(&self, hashed: &HashedMessage) -> Signature {
+        Signature((hashed.0 * self.0).into())
+    }
 
-        // Multiply point by sk
-        let e = h * self.0;
-        Signature(e.into())
+    /// Sign the 32-byte digest of a message.
+    ///
+    /// The digest is hashed to the curve under its own domain separation tag,
+    /// so the signature can't be mistaken for a [`SecretKey::sign`] signature
+    /// over a 32-byte message, and the other way around.
+    pub fn sign_prehashed(&self, digest: &[u8; 32]) -> Signature {
+        Signature((h0_prehashed(digest) * self.0).into())
     }
 
     /// Sign a message using the default multi-signature behavior.
+    ///
+    /// With the `hardened` feature, the signature is computed and checked
+    /// like with [`SecretKey::sign`], against the public key derived from
+    /// `self` and the coefficient of `pk`.
+    ///
+    /// # Panics
+    ///
+    /// With the `hardened` feature, panics if the self-check fails instead of
+    /// returning a faulty signature. `SecretKey::sign_multisig_hardened`
+    /// returns an error instead.
     pub fn sign_multisig(
         &self,
         pk: &PublicKey,
         msg: &[u8],
     ) -> MultisigSignature {
-        let mut sig = self.sign(msg);
-
         // Turn signature into its modified construction,
         // which provides protection against rogue-key attacks.
         let t = h1(pk);
-        sig.0 = (sig.0 * t).into();
 
-        MultisigSignature(sig.0)
+        #[cfg(feature = "hardened")]
+        {
+            let mut sk_t = self.0 * t;
+            let k = message_blinding(&sk_t, msg);
+            let sig = MultisigSignature(blinded_mul(&h0(msg), &sk_t, k).into());
+            sk_t.zeroize();
+
+            MultisigPublicKey((PublicKey::from(self).0 * t).into())
+                .verify(&sig, msg)
+                .expect("hardened signature self-check failed");
+            sig
+        }
+
+        #[cfg(not(feature = "hardened"))]
+        MultisigSignature((self.sign(msg).0 * t).into())
     }
 
-    /// Sign a message using the insecure v1 multi-signature scheme.
+    /// Sign a message using the default multi-signature behavior, checking
+    /// that `pk` is the [`PublicKey`] of `self`.
+    ///
+    /// To avoid deriving the public key on every call, use a
+    /// [`Keypair`](crate::Keypair) instead.
+    ///
+    /// # Errors
     ///
-    /// This path is considered insecure and is intentionally gated behind
-    /// the `insecure-v1-signing` feature.
-    #[cfg(feature = "insecure-v1-signing")]
-    pub fn sign_multisig_insecure(
+    /// Returns [`Error::KeyMismatch`] if `pk` does not belong to `self`.
+    pub fn sign_multisig_checked(
         &self,
         pk: &PublicKey,
         msg: &[u8],
-    ) -> MultisigSignature {
-        let mut sig = self.sign_insecure(msg);
+    ) -> Result<MultisigSignature, Error> {
+        if PublicKey::from(self) != *pk {
+            return Err(Error::KeyMismatch);
+        }
+        Ok(self.sign_multisig(pk, msg))
+    }
 
-        // Turn signature into its modified construction,
-        // which provides protection against rogue-key attacks.
-        let t = h1_insecure(pk);
-        sig.0 = (sig.0 * t).into();
+    /// Sign a message using the hardened single-signature path.
+    ///
+    /// The produced signature is identical to the one returned by
+    /// [`SecretKey::sign`], but:
+    /// - the secret scalar is blinded with a random multiple of the group
+    ///   order before the point multiplication,
+    /// - the intermediate points are zeroized,
+    /// - the signature is verified against `pk` before it is returned.
+    ///
+    /// `pk` must be the stored [`PublicKey`] of `self`, not one derived from
+    /// it on the fly: deriving it multiplies by the unblinded scalar, and
+    /// derives a key matching the faulty signature if the scalar itself was
+    /// corrupted in memory. A [`Keypair`](crate::Keypair) caches its public
+    /// key for this, see
+    /// [`Keypair::sign_hardened`](crate::Keypair::sign_hardened).
+    ///
+    /// With the `hardened` feature, [`SecretKey::sign`] is hardened as well,
+    /// but it has no random number generator for the blinding and no stored
+    /// public key to check against, and can only panic when the check fails,
+    /// since enabling a cargo feature must not change its signature.
+    ///
+    /// The self-check costs a pairing check per signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SelfCheckFailed`] if the signature does not verify
+    /// under `pk`, e.g. because a fault was injected during signing. This
+    /// includes the case where `pk` is not the public key matching `self`.
+    #[cfg(feature = "hardened")]
+    pub fn sign_hardened<R>(
+        &self,
+        rng: &mut R,
+        pk: &PublicKey,
+        msg: &[u8],
+    ) -> Result<Signature, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        let k = rng.next_u64();
+        let sig = Signature(blinded_mul(&h0(msg), &self.0, k).into());
+
+        pk.verify(&sig, msg).map_err(|_| Error::SelfCheckFailed)?;
+
+        Ok(sig)
+    }
+
+    /// Sign a message using the hardened multi-signature path.
+    ///
+    /// This is the hardened counterpart of [`SecretKey::sign_multisig`], see
+    /// [`SecretKey::sign_hardened`] for the protections applied and the
+    /// requirements on `pk`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SelfCheckFailed`] if the signature does not verify
+    /// under `pk`. This includes the case where `pk` is not the public key
+    /// matching `self`.
+    #[cfg(feature = "hardened")]
+    pub fn sign_multisig_hardened<R>(
+        &self,
+        rng: &mut R,
+        pk: &PublicKey,
+        msg: &[u8],
+    ) -> Result<MultisigSignature, Error>
+    where
+        R: RngCore + CryptoRng,
+    {
+        // Fold the rogue-key coefficient into the scalar so that it is
+        // blinded together with the secret key.
+        let t = h1(pk);
+        let mut sk_t = self.0 * t;
+        let k = rng.next_u64();
+        let sig = MultisigSignature(blinded_mul(&h0(msg), &sk_t, k).into());
+        sk_t.zeroize();
+
+        MultisigPublicKey((pk.0 * t).into())
+            .verify(&sig, msg)
+            .map_err(|_| Error::SelfCheckFailed)?;
 
-        MultisigSignature(sig.0)
+        Ok(sig)
     }
 }
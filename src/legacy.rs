@@ -0,0 +1,221 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Insecure v1 BLS scheme, kept for verifying historical data.
+//!
+//! The v1 scheme maps messages to G1 by multiplying the generator with a hash
+//! of the message, which allows linear forgery combinations. It must not be
+//! used for new signatures.
+//!
+//! The module has its own key and signature types, so that v1 and v2 keys and
+//! signatures can't be mixed up by accident. Keys can be converted explicitly
+//! from their v2 counterparts, since the same key material was used under
+//! both schemes.
+//!
+//! Verification is gated behind the `insecure-v1-verify` feature, signing
+//! additionally behind the `insecure-v1-signing` feature.
+
+use crate::keys::public::{
+    aggregate_keys, g2_from_bytes, is_valid, verify_point,
+};
+use crate::signatures::{g1_from_bytes, is_valid as is_valid_sig};
+use crate::{Error, SecretKey};
+
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective, G2Affine};
+use dusk_bytes::Serializable;
+
+#[inline]
+fn h0(msg: &[u8]) -> G1Affine {
+    // Insecure v1 map used by historical blocks/transactions.
+    (G1Affine::generator() * BlsScalar::hash_to_scalar(msg)).into()
+}
+
+#[inline]
+fn h1(pk: &PublicKey) -> BlsScalar {
+    // Insecure v1 function used for multisig coefficients.
+    BlsScalar::hash_to_scalar(&pk.to_bytes())
+}
+
+/// A BLS public key for the insecure v1 scheme.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PublicKey(G2Affine);
+
+impl Serializable<96> for PublicKey {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        Ok(Self(g2_from_bytes(bytes)?))
+    }
+}
+
+impl From<&crate::PublicKey> for PublicKey {
+    /// Use a [`crate::PublicKey`] for verifying v1 signatures.
+    fn from(pk: &crate::PublicKey) -> Self {
+        Self(pk.0)
+    }
+}
+
+impl From<&SecretKey> for PublicKey {
+    /// Generates a new [`PublicKey`] from a [`SecretKey`].
+    fn from(sk: &SecretKey) -> Self {
+        Self::from(&crate::PublicKey::from(sk))
+    }
+}
+
+impl PublicKey {
+    /// Verify a v1 [`Signature`].
+    pub fn verify(&self, sig: &Signature, msg: &[u8]) -> Result<(), Error> {
+        verify_point(&self.0, &sig.0, &h0(msg))
+    }
+
+    /// Return `pk * t` for the v1 multisig construction, where `t` is the v1
+    /// coefficient hash of `pk`.
+    pub fn pk_t(&self) -> G2Affine {
+        (self.0 * h1(self)).into()
+    }
+
+    /// Returns true if the inner point is valid, see
+    /// [`crate::PublicKey::is_valid`].
+    pub fn is_valid(&self) -> bool {
+        is_valid(&self.0)
+    }
+}
+
+/// Aggregated form of v1 BLS public keys.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct MultisigPublicKey(G2Affine);
+
+impl Serializable<96> for MultisigPublicKey {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Self::Error> {
+        Ok(Self(g2_from_bytes(bytes)?))
+    }
+}
+
+impl MultisigPublicKey {
+    /// Aggregate a set of v1 [`PublicKey`]s using the v1 multisig
+    /// coefficients.
+    ///
+    /// # Errors
+    ///
+    /// The aggregation errors with [`Error::NoKeysProvided`] when an empty
+    /// slice is passed, or with [`Error::InvalidKeyAt`] when one of the
+    /// [`PublicKey`]s is made of the identity or an otherwise invalid point.
+    ///
+    /// Unlike [`crate::MultisigPublicKey::aggregate`], duplicate keys are
+    /// accepted, so that historical aggregates remain reproducible.
+    pub fn aggregate(pks: &[PublicKey]) -> Result<Self, Error> {
+        aggregate_keys(pks, |pk| pk.0, PublicKey::pk_t, false).map(Self)
+    }
+
+    /// Verify a v1 [`MultisigSignature`].
+    pub fn verify(
+        &self,
+        sig: &MultisigSignature,
+        msg: &[u8],
+    ) -> Result<(), Error> {
+        verify_point(&self.0, &sig.0, &h0(msg))
+    }
+}
+
+/// A BLS signature of the insecure v1 single-signature scheme.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Signature(pub(crate) G1Affine);
+
+impl Serializable<48> for Signature {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(g1_from_bytes(bytes)?))
+    }
+}
+
+impl Signature {
+    /// Returns true if the inner point is valid, see
+    /// [`crate::Signature::is_valid`].
+    pub fn is_valid(&self) -> bool {
+        is_valid_sig(&self.0)
+    }
+}
+
+/// A BLS signature of the insecure v1 multi-signature scheme.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+pub struct MultisigSignature(pub(crate) G1Affine);
+
+impl Serializable<48> for MultisigSignature {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(g1_from_bytes(bytes)?))
+    }
+}
+
+impl MultisigSignature {
+    /// Aggregate a set of signatures by adding up the points.
+    pub fn aggregate(&self, sigs: &[MultisigSignature]) -> Self {
+        Self(
+            sigs.iter().fold(self.0, |acc, sig| {
+                (acc + G1Projective::from(sig.0)).into()
+            }),
+        )
+    }
+
+    /// Returns true if the inner point is valid, see
+    /// [`crate::MultisigSignature::is_valid`].
+    pub fn is_valid(&self) -> bool {
+        is_valid_sig(&self.0)
+    }
+}
+
+#[cfg(feature = "insecure-v1-signing")]
+impl SecretKey {
+    /// Sign a message using the insecure v1 single-signature scheme.
+    ///
+    /// This path is considered insecure and is intentionally gated behind
+    /// the `insecure-v1-signing` feature.
+    pub fn sign_insecure(&self, msg: &[u8]) -> Signature {
+        // Hash message
+        let h = h0(msg);
+
+        // Multiply point by sk
+        let e = h * self.0;
+        Signature(e.into())
+    }
+
+    /// Sign a message using the insecure v1 multi-signature scheme.
+    ///
+    /// This path is considered insecure and is intentionally gated behind
+    /// the `insecure-v1-signing` feature.
+    pub fn sign_multisig_insecure(
+        &self,
+        pk: &PublicKey,
+        msg: &[u8],
+    ) -> MultisigSignature {
+        let sig = self.sign_insecure(msg);
+
+        // Turn signature into its modified construction,
+        // which provides protection against rogue-key attacks.
+        let t = h1(pk);
+        MultisigSignature((sig.0 * t).into())
+    }
+}
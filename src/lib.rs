@@ -9,28 +9,102 @@
 //! Implementation of BLS signatures on the BLS12-381 curve.
 //! Reference paper: <https://crypto.stanford.edu/~dabo/pubs/papers/BLSmultisig.html>
 
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod batch;
+mod blind;
 mod error;
 mod hash;
+mod ibs;
 mod keys;
 mod signatures;
+mod typed;
+mod versioned;
+mod vrf;
+
+// Free-function schemes, namespaced rather than re-exported at the root.
+pub mod beacon;
+pub mod ibe;
 
+pub use blind::{BlindSignature, BlindedMessage, BlindingFactor};
 pub use error::Error;
+pub use hash::{
+    HashedMessage, MessageHasher, encode_to_g1, hash_to_g1, hash_to_scalar,
+};
+pub use ibs::{ExposedIdentitySecretKey, IdentitySecretKey, IdentitySignature};
 pub use keys::{
-    public::{MultisigPublicKey, PublicKey},
-    secret::SecretKey,
+    keypair::{ExposedKeypair, Keypair},
+    public::{Fingerprint, MultisigPublicKey, PublicKey},
+    secret::{ExposedSecretKey, SecretKey},
 };
 pub use signatures::{MultisigSignature, Signature};
+pub use typed::{EncodeField, MessageEncoder, SignableMessage};
+pub use versioned::{
+    SignatureScheme, VersionPolicy, VersionedMultisigSignature,
+    VersionedSignature,
+};
+pub use vrf::{VrfOutput, VrfProof};
+
+#[cfg(feature = "rkyv-impl")]
+mod archived;
+
+#[cfg(feature = "child-keys")]
+mod child;
+
+#[cfg(feature = "cose")]
+mod cose;
+#[cfg(feature = "cose")]
+pub use crate::cose::COSE_ALGORITHM;
+
+#[cfg(feature = "did-key")]
+mod did;
+#[cfg(feature = "did-key")]
+pub use crate::did::{VerificationMethod, resolve_did_key};
+
+#[cfg(feature = "hardened")]
+mod hardened;
+
+#[cfg(feature = "bech32")]
+mod text;
+
+#[cfg(feature = "insecure-v1-verify")]
+pub mod legacy;
+
+#[cfg(feature = "jwk")]
+mod jwk;
+#[cfg(feature = "jwk")]
+pub use crate::jwk::Jwk;
+
+#[cfg(any(feature = "mnemonic", feature = "child-keys"))]
+mod kdf;
+
+#[cfg(any(feature = "jwk", feature = "cose"))]
+mod key_params;
+
+#[cfg(feature = "mnemonic")]
+mod mnemonic;
+#[cfg(feature = "mnemonic")]
+pub use crate::mnemonic::Mnemonic;
+
+#[cfg(feature = "pkcs8")]
+mod pkcs8;
+#[cfg(feature = "pkcs8")]
+pub use crate::pkcs8::DFINITY_BLS12_381_G2_OID;
 
+#[cfg(feature = "serde")]
+pub mod serde_encoding;
 #[cfg(feature = "serde")]
 mod serde_support;
 
+#[cfg(feature = "signature-traits")]
+mod signature_traits;
+
 #[cfg(feature = "rkyv-impl")]
-pub use crate::keys::{
-    public::{
-        ArchivedMultisigPublicKey, ArchivedPublicKey,
-        MultisigPublicKeyResolver, PublicKeyResolver,
-    },
-    secret::{ArchivedSecretKey, SecretKeyResolver},
+pub use crate::keys::public::{
+    ArchivedMultisigPublicKey, ArchivedPublicKey, MultisigPublicKeyResolver,
+    PublicKeyResolver,
 };
 
 #[cfg(feature = "rkyv-impl")]
@@ -38,3 +112,8 @@ pub use crate::signatures::{
     ArchivedMultisigSignature, ArchivedSignature, MultisigSignatureResolver,
     SignatureResolver,
 };
+
+#[cfg(feature = "rkyv-impl")]
+pub use crate::vrf::{
+    ArchivedVrfOutput, ArchivedVrfProof, VrfOutputResolver, VrfProofResolver,
+};
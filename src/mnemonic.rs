@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! BIP-39 mnemonic backups of secret keys.
+//!
+//! Entropy is encoded as a phrase of English words with a checksum. The
+//! phrase and an optional passphrase are stretched into a 64-byte seed as
+//! specified by BIP-39, and the seed into a [`SecretKey`] with the `KeyGen`
+//! procedure of the IETF BLS signature draft, as also used by EIP-2333 for
+//! master keys.
+
+use core::fmt;
+use core::str::FromStr;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::BadLength;
+use rand_core::{CryptoRng, RngCore};
+use sha2_v010::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use crate::kdf::{OKM_SIZE, hkdf_scalar};
+use crate::{Error, SecretKey};
+
+/// Number of words of generated mnemonics, encoding 256 bits of entropy.
+const WORD_COUNT: usize = 24;
+
+/// Minimum size of the input keying material of `KeyGen`.
+const MIN_SEED_SIZE: usize = 32;
+
+const KEYGEN_SALT: &[u8] = b"BLS-SIG-KEYGEN-SALT-";
+
+/// A BIP-39 mnemonic phrase in English.
+///
+/// The phrase is as secret as the keys derived from it. It is zeroized on
+/// drop and redacted from the [`Debug`] output, and can be written out
+/// through its [`Display`](fmt::Display) implementation.
+///
+/// ```
+/// use bls12_381_bls::{Mnemonic, PublicKey, SecretKey};
+/// use rand::rngs::OsRng;
+///
+/// let mnemonic = Mnemonic::generate(&mut OsRng);
+/// let sk = SecretKey::from_mnemonic(&mnemonic, "passphrase");
+///
+/// // Restore the key from the backed up phrase.
+/// let restored: Mnemonic = mnemonic.to_string().parse().unwrap();
+/// let restored = SecretKey::from_mnemonic(&restored, "passphrase");
+/// assert_eq!(PublicKey::from(&restored), PublicKey::from(&sk));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mnemonic(bip39::Mnemonic);
+
+impl Mnemonic {
+    /// Generate a new 24 word mnemonic.
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mnemonic = bip39::Mnemonic::generate_in_with(
+            rng,
+            bip39::Language::English,
+            WORD_COUNT,
+        )
+        .expect("24 words is a valid word count");
+        Self(mnemonic)
+    }
+
+    /// Encode `entropy` as a mnemonic.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::InvalidMnemonic`] if the entropy isn't 16, 20,
+    /// 24, 28 or 32 bytes long.
+    pub fn from_entropy(entropy: &[u8]) -> Result<Self, Error> {
+        bip39::Mnemonic::from_entropy(entropy)
+            .map(Self)
+            .map_err(|_| Error::InvalidMnemonic)
+    }
+
+    /// Parse a mnemonic phrase, validating its checksum.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::UnknownWord`] if a word is not in the English
+    /// word list, and with [`Error::InvalidMnemonic`] if the number of words
+    /// or the checksum are wrong.
+    pub fn parse(phrase: &str) -> Result<Self, Error> {
+        bip39::Mnemonic::parse_in(bip39::Language::English, phrase)
+            .map(Self)
+            .map_err(|err| match err {
+                bip39::Error::UnknownWord(index) => {
+                    Error::UnknownWord { index }
+                }
+                _ => Error::InvalidMnemonic,
+            })
+    }
+
+    /// Number of words of the phrase.
+    pub fn word_count(&self) -> usize {
+        self.0.word_count()
+    }
+
+    /// Stretch the phrase and `passphrase` into a BIP-39 seed.
+    pub fn to_seed(&self, passphrase: &str) -> Zeroizing<[u8; 64]> {
+        Zeroizing::new(self.0.to_seed(passphrase))
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = Error;
+
+    fn from_str(phrase: &str) -> Result<Self, Error> {
+        Self::parse(phrase)
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Mnemonic").field(&"<redacted>").finish()
+    }
+}
+
+impl SecretKey {
+    /// Derive a [`SecretKey`] from a seed of at least 32 bytes, using the
+    /// `KeyGen` procedure of the IETF BLS signature draft with an empty
+    /// `key_info`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the seed is shorter than 32 bytes.
+    pub fn from_seed(seed: &[u8]) -> Result<Self, Error> {
+        if seed.len() < MIN_SEED_SIZE {
+            return Err(Error::bad_length(seed.len(), MIN_SEED_SIZE));
+        }
+
+        let mut salt = Sha256::digest(KEYGEN_SALT);
+        loop {
+            // IKM || I2OSP(0, 1) and key_info || I2OSP(L, 2)
+            let sk = hkdf_scalar(
+                &salt,
+                &[seed, &[0]],
+                &[&(OKM_SIZE as u16).to_be_bytes()],
+            );
+            if sk != BlsScalar::zero() {
+                return Ok(Self(sk));
+            }
+
+            salt = Sha256::digest(salt);
+        }
+    }
+
+    /// Derive the [`SecretKey`] backed up by a mnemonic, with the given
+    /// passphrase.
+    ///
+    /// The passphrase may be empty. A different passphrase yields a different
+    /// key, without failing.
+    pub fn from_mnemonic(mnemonic: &Mnemonic, passphrase: &str) -> Self {
+        Self::from_seed(&mnemonic.to_seed(passphrase)[..])
+            .expect("BIP-39 seeds are 64 bytes long")
+    }
+}
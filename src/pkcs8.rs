@@ -0,0 +1,106 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! DER and PEM containers for keys.
+//!
+//! Public keys are encoded as a `SubjectPublicKeyInfo` holding the compressed
+//! G2 point, secret keys as a PKCS#8 `PrivateKeyInfo` holding the scalar bytes
+//! wrapped in an OCTET STRING, the same way RFC 8410 wraps Ed25519 keys. Both
+//! use [`DFINITY_BLS12_381_G2_OID`] as algorithm identifier, without
+//! parameters, which is not a standard identifier: the containers are only
+//! meant to be read back by this crate.
+//!
+//! The conversions are provided through the traits of the [`pkcs8`] crate:
+//! [`EncodePublicKey`] and [`DecodePublicKey`] for [`PublicKey`],
+//! [`EncodePrivateKey`] for [`ExposedSecretKey`] and [`DecodePrivateKey`] for
+//! [`SecretKey`].
+//!
+//! [`pkcs8`]: ::pkcs8
+//! [`DecodePublicKey`]: ::pkcs8::DecodePublicKey
+//! [`DecodePrivateKey`]: ::pkcs8::DecodePrivateKey
+
+use ::pkcs8::der::asn1::{BitStringRef, OctetStringRef};
+use ::pkcs8::der::{Decode, Encode};
+use ::pkcs8::spki::{self, AlgorithmIdentifierRef};
+use ::pkcs8::{
+    Document, EncodePrivateKey, EncodePublicKey, ObjectIdentifier,
+    PrivateKeyInfo, SecretDocument, SubjectPublicKeyInfoRef,
+};
+use dusk_bytes::Serializable;
+use zeroize::Zeroizing;
+
+use crate::{ExposedSecretKey, PublicKey, SecretKey};
+
+/// Object identifier of BLS12-381 keys whose public key lives in G2.
+///
+/// The identifier sits under the private enterprise arc of DFINITY
+/// (`1.3.6.1.4.1.44668`), not under an IETF registered arc, and this crate
+/// uses it without parameters. Other implementations are not expected to
+/// accept these containers, so they are not interoperable and should only be
+/// exchanged between users of this crate. Since public keys of this crate
+/// always live in G2, there is no identifier for G1 public keys.
+pub const DFINITY_BLS12_381_G2_OID: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.3.6.1.4.1.44668.5.3.1.2");
+
+const ALGORITHM: AlgorithmIdentifierRef<'static> = AlgorithmIdentifierRef {
+    oid: DFINITY_BLS12_381_G2_OID,
+    parameters: None,
+};
+
+fn check_algorithm(algorithm: &AlgorithmIdentifierRef) -> spki::Result<()> {
+    algorithm.assert_algorithm_oid(DFINITY_BLS12_381_G2_OID)?;
+    if algorithm.parameters.is_some() {
+        return Err(spki::Error::KeyMalformed);
+    }
+    Ok(())
+}
+
+impl EncodePublicKey for PublicKey {
+    fn to_public_key_der(&self) -> spki::Result<Document> {
+        let bytes = self.to_bytes();
+        let info = SubjectPublicKeyInfoRef {
+            algorithm: ALGORITHM,
+            subject_public_key: BitStringRef::new(0, &bytes)?,
+        };
+        Document::try_from(info)
+    }
+}
+
+impl TryFrom<SubjectPublicKeyInfoRef<'_>> for PublicKey {
+    type Error = spki::Error;
+
+    fn try_from(info: SubjectPublicKeyInfoRef<'_>) -> spki::Result<Self> {
+        check_algorithm(&info.algorithm)?;
+        let bytes = info
+            .subject_public_key
+            .as_bytes()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(spki::Error::KeyMalformed)?;
+        Self::from_bytes(bytes).map_err(|_| spki::Error::KeyMalformed)
+    }
+}
+
+impl EncodePrivateKey for ExposedSecretKey<'_> {
+    fn to_pkcs8_der(&self) -> ::pkcs8::Result<SecretDocument> {
+        let bytes = Zeroizing::new(self.to_bytes());
+        let private_key =
+            Zeroizing::new(OctetStringRef::new(&bytes[..])?.to_der()?);
+        SecretDocument::try_from(PrivateKeyInfo::new(ALGORITHM, &private_key))
+    }
+}
+
+impl TryFrom<PrivateKeyInfo<'_>> for SecretKey {
+    type Error = ::pkcs8::Error;
+
+    fn try_from(info: PrivateKeyInfo<'_>) -> ::pkcs8::Result<Self> {
+        check_algorithm(&info.algorithm)?;
+        let bytes = OctetStringRef::from_der(info.private_key)?
+            .as_bytes()
+            .try_into()
+            .map_err(|_| ::pkcs8::Error::KeyMalformed)?;
+        Self::from_bytes(bytes).map_err(|_| ::pkcs8::Error::KeyMalformed)
+    }
+}
@@ -0,0 +1,305 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Adaptors selecting the encoding of keys and signatures with
+//! `#[serde(with = "...")]`.
+//!
+//! The default serde implementations use bs58 strings for human readable
+//! formats and fixed-size bytes otherwise. The modules here pin a specific
+//! encoding regardless of the format:
+//!
+//! - [`hex`], [`base64`] and [`bs58`] encode the compressed bytes as a string.
+//! - [`uncompressed`] encodes the uncompressed points as bytes, which are
+//!   faster to decode. The points are still fully validated.
+//! - [`raw`] encodes the raw bytes of [`PublicKey::to_raw_bytes`], which are
+//!   decoded without any validation and must only be used for trusted data.
+//!
+//! ```
+//! use bls12_381_bls::{PublicKey, Signature};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Vote {
+//!     #[serde(with = "bls12_381_bls::serde_encoding::hex")]
+//!     voter: PublicKey,
+//!     #[serde(with = "bls12_381_bls::serde_encoding::base64")]
+//!     signature: Signature,
+//! }
+//! ```
+//!
+//! [`SecretKey`]s can be deserialized with any of the adaptors, but only an
+//! [`ExposedSecretKey`] can be serialized, using `serialize_with`. The
+//! intermediate encodings of secret keys are zeroized.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use dusk_bls12_381::{G1Affine, G2Affine};
+use dusk_bytes::{BadLength, Serializable};
+use serde::de::{Error as SerdeError, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serializer};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{
+    Error, ExposedSecretKey, MultisigPublicKey, MultisigSignature, PublicKey,
+    SecretKey, Signature,
+};
+
+/// Types that can be serialized with the adaptors of this module.
+pub trait Encode {
+    #[doc(hidden)]
+    fn encode(&self) -> Zeroizing<Vec<u8>>;
+
+    #[doc(hidden)]
+    fn encode_uncompressed(&self) -> Zeroizing<Vec<u8>>;
+
+    #[doc(hidden)]
+    fn encode_raw(&self) -> Zeroizing<Vec<u8>>;
+}
+
+/// Types that can be deserialized with the adaptors of this module.
+pub trait Decode: Sized {
+    #[doc(hidden)]
+    fn decode(bytes: &[u8]) -> Result<Self, Error>;
+
+    #[doc(hidden)]
+    fn decode_uncompressed(bytes: &[u8]) -> Result<Self, Error>;
+
+    #[doc(hidden)]
+    fn decode_raw(bytes: &[u8]) -> Result<Self, Error>;
+}
+
+// The largest encoding of any supported type, the raw bytes of a G2 point.
+const MAX_SIZE: usize = G2Affine::RAW_SIZE;
+
+fn array<const N: usize>(bytes: &[u8]) -> Result<[u8; N], Error> {
+    bytes
+        .try_into()
+        .map_err(|_| Error::bad_length(bytes.len(), N))
+}
+
+macro_rules! impl_point_encoding {
+    ($ty:ty, $point:ty) => {
+        impl Encode for $ty {
+            fn encode(&self) -> Zeroizing<Vec<u8>> {
+                Zeroizing::new(self.to_bytes().to_vec())
+            }
+
+            fn encode_uncompressed(&self) -> Zeroizing<Vec<u8>> {
+                Zeroizing::new(self.to_uncompressed().to_vec())
+            }
+
+            fn encode_raw(&self) -> Zeroizing<Vec<u8>> {
+                Zeroizing::new(self.0.to_raw_bytes().to_vec())
+            }
+        }
+
+        impl Decode for $ty {
+            fn decode(bytes: &[u8]) -> Result<Self, Error> {
+                Self::from_bytes(&array(bytes)?).map_err(Error::from)
+            }
+
+            fn decode_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+                Self::from_uncompressed(&array(bytes)?)
+            }
+
+            fn decode_raw(bytes: &[u8]) -> Result<Self, Error> {
+                let bytes = array::<{ <$point>::RAW_SIZE }>(bytes)?;
+                // SAFETY: the length is checked, and the `raw` adaptor is
+                // documented to only be used with trusted data.
+                Ok(Self(unsafe { <$point>::from_slice_unchecked(&bytes) }))
+            }
+        }
+    };
+}
+
+impl_point_encoding!(PublicKey, G2Affine);
+impl_point_encoding!(MultisigPublicKey, G2Affine);
+impl_point_encoding!(Signature, G1Affine);
+impl_point_encoding!(MultisigSignature, G1Affine);
+
+impl Encode for ExposedSecretKey<'_> {
+    fn encode(&self) -> Zeroizing<Vec<u8>> {
+        let mut bytes = self.to_bytes();
+        let encoded = Zeroizing::new(bytes.to_vec());
+        bytes.zeroize();
+        encoded
+    }
+
+    fn encode_uncompressed(&self) -> Zeroizing<Vec<u8>> {
+        self.encode()
+    }
+
+    fn encode_raw(&self) -> Zeroizing<Vec<u8>> {
+        self.encode()
+    }
+}
+
+impl Decode for SecretKey {
+    fn decode(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes(&array(bytes)?)
+    }
+
+    fn decode_uncompressed(bytes: &[u8]) -> Result<Self, Error> {
+        Self::decode(bytes)
+    }
+
+    fn decode_raw(bytes: &[u8]) -> Result<Self, Error> {
+        Self::decode(bytes)
+    }
+}
+
+macro_rules! string_encoding {
+    ($(#[$doc:meta])* $name:ident, $encode:expr, $decode:expr) => {
+        $(#[$doc])*
+        pub mod $name {
+            use super::*;
+
+            /// Serialize `value` as a string.
+            pub fn serialize<T: Encode, S: Serializer>(
+                value: &T,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                let encode: fn(&[u8]) -> String = $encode;
+                let encoded = Zeroizing::new(encode(&value.encode()));
+                serializer.serialize_str(&encoded)
+            }
+
+            /// Deserialize a value from a string.
+            pub fn deserialize<'de, T: Decode, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<T, D::Error> {
+                let s = Zeroizing::new(String::deserialize(deserializer)?);
+                let decode: fn(&str) -> Result<Vec<u8>, String> = $decode;
+                let bytes =
+                    Zeroizing::new(decode(&s).map_err(SerdeError::custom)?);
+                T::decode(&bytes).map_err(SerdeError::custom)
+            }
+        }
+    };
+}
+
+string_encoding!(
+    /// Lowercase hex strings of the compressed bytes.
+    hex,
+    |bytes| ::hex::encode(bytes),
+    |s| ::hex::decode(s).map_err(|err| alloc::format!("{err}"))
+);
+
+string_encoding!(
+    /// Standard, padded base64 strings of the compressed bytes.
+    base64,
+    |bytes| {
+        use ::base64::Engine;
+        ::base64::engine::general_purpose::STANDARD.encode(bytes)
+    },
+    |s| {
+        use ::base64::Engine;
+        ::base64::engine::general_purpose::STANDARD
+            .decode(s)
+            .map_err(|err| alloc::format!("{err}"))
+    }
+);
+
+string_encoding!(
+    /// bs58 strings of the compressed bytes, as used by the default human
+    /// readable encoding.
+    bs58,
+    |bytes| ::bs58::encode(bytes).into_string(),
+    |s| {
+        ::bs58::decode(s)
+            .into_vec()
+            .map_err(|err| alloc::format!("{err}"))
+    }
+);
+
+/// Uncompressed points as bytes.
+pub mod uncompressed {
+    use super::*;
+
+    /// Serialize `value` as its uncompressed bytes.
+    pub fn serialize<T: Encode, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&value.encode_uncompressed())
+    }
+
+    /// Deserialize a value from its uncompressed bytes.
+    pub fn deserialize<'de, T: Decode, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+        T::decode_uncompressed(&bytes).map_err(SerdeError::custom)
+    }
+}
+
+/// Raw points as bytes, see [`PublicKey::to_raw_bytes`].
+///
+/// Decoding skips every check, including that the point is on the curve and
+/// in the prime-order subgroup, like [`PublicKey::from_slice_unchecked`].
+/// This is only meant for trusted data where performance is critical, such
+/// as a local cache, never for data received from others.
+pub mod raw {
+    use super::*;
+
+    /// Serialize `value` as its raw bytes.
+    pub fn serialize<T: Encode, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&value.encode_raw())
+    }
+
+    /// Deserialize a value from its raw bytes, without validating it.
+    pub fn deserialize<'de, T: Decode, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+        T::decode_raw(&bytes).map_err(SerdeError::custom)
+    }
+}
+
+/// Visits bytes, given either as a byte slice or as a sequence.
+///
+/// Sequences longer than any supported encoding are rejected without being
+/// buffered.
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Zeroizing<Vec<u8>>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bytes")
+    }
+
+    fn visit_bytes<E: SerdeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Ok(Zeroizing::new(v.to_vec()))
+    }
+
+    fn visit_byte_buf<E: SerdeError>(
+        self,
+        v: Vec<u8>,
+    ) -> Result<Self::Value, E> {
+        Ok(Zeroizing::new(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut seq: A,
+    ) -> Result<Self::Value, A::Error> {
+        let hint = seq.size_hint().unwrap_or(0);
+        let mut bytes = Zeroizing::new(Vec::with_capacity(hint.min(MAX_SIZE)));
+        while let Some(byte) = seq.next_element()? {
+            if bytes.len() == MAX_SIZE {
+                return Err(SerdeError::invalid_length(MAX_SIZE + 1, &self));
+            }
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
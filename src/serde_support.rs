@@ -4,27 +4,95 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-extern crate alloc;
-
 use alloc::format;
 use alloc::string::String;
+use core::fmt;
 
-use bs58;
 use dusk_bytes::Serializable;
-use serde::de::Error as SerdeError;
+use serde::de::{Error as SerdeError, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    MultisigPublicKey, MultisigSignature, PublicKey, SecretKey, Signature,
+    ExposedKeypair, ExposedSecretKey, Keypair, MultisigPublicKey,
+    MultisigSignature, PublicKey, SecretKey, Signature,
 };
 
+/// Serialize bytes as a bs58 string for human readable formats, and as a
+/// fixed-size tuple of bytes otherwise.
+fn serialize_bytes<S: Serializer>(
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        let s = bs58::encode(bytes).into_string();
+        serializer.serialize_str(&s)
+    } else {
+        let mut tuple = serializer.serialize_tuple(bytes.len())?;
+        for byte in bytes {
+            tuple.serialize_element(byte)?;
+        }
+        tuple.end()
+    }
+}
+
+/// Deserialize bytes serialized with [`serialize_bytes`].
+fn deserialize_bytes<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    if deserializer.is_human_readable() {
+        let s = String::deserialize(deserializer)?;
+        let decoded =
+            bs58::decode(&s).into_vec().map_err(SerdeError::custom)?;
+        let decoded_len = decoded.len();
+        let byte_length_str = format!("{N}");
+        decoded.try_into().map_err(|_| {
+            SerdeError::invalid_length(decoded_len, &byte_length_str.as_str())
+        })
+    } else {
+        deserializer.deserialize_tuple(N, ArrayVisitor::<N>)
+    }
+}
+
+/// Visits a fixed-size array of bytes, given either as a sequence or as a
+/// byte slice.
+struct ArrayVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for ArrayVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{N} bytes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(
+        self,
+        mut seq: A,
+    ) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| SerdeError::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(SerdeError::invalid_length(N + 1, &self));
+        }
+        Ok(bytes)
+    }
+
+    fn visit_bytes<E: SerdeError>(self, v: &[u8]) -> Result<Self::Value, E> {
+        v.try_into()
+            .map_err(|_| SerdeError::invalid_length(v.len(), &self))
+    }
+}
+
 impl Serialize for PublicKey {
     fn serialize<S: Serializer>(
         &self,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        let s = bs58::encode(self.to_bytes()).into_string();
-        serializer.serialize_str(&s)
+        serialize_bytes(&self.to_bytes(), serializer)
     }
 }
 
@@ -32,14 +100,7 @@ impl<'de> Deserialize<'de> for PublicKey {
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
     ) -> Result<Self, D::Error> {
-        let s = String::deserialize(deserializer)?;
-        let decoded =
-            bs58::decode(&s).into_vec().map_err(SerdeError::custom)?;
-        let decoded_len = decoded.len();
-        let byte_length_str = format!("{}", Self::SIZE);
-        let bytes: [u8; Self::SIZE] = decoded.try_into().map_err(|_| {
-            SerdeError::invalid_length(decoded_len, &byte_length_str.as_str())
-        })?;
+        let bytes = deserialize_bytes(deserializer)?;
         PublicKey::from_bytes(&bytes)
             .map_err(|err| SerdeError::custom(format!("{err:?}")))
     }
@@ -50,8 +111,7 @@ impl Serialize for MultisigPublicKey {
         &self,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        let s = bs58::encode(self.to_bytes()).into_string();
-        serializer.serialize_str(&s)
+        serialize_bytes(&self.to_bytes(), serializer)
     }
 }
 
@@ -59,14 +119,7 @@ impl<'de> Deserialize<'de> for MultisigPublicKey {
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
     ) -> Result<Self, D::Error> {
-        let s = String::deserialize(deserializer)?;
-        let decoded =
-            bs58::decode(&s).into_vec().map_err(SerdeError::custom)?;
-        let decoded_len = decoded.len();
-        let byte_length_str = format!("{}", Self::SIZE);
-        let bytes: [u8; Self::SIZE] = decoded.try_into().map_err(|_| {
-            SerdeError::invalid_length(decoded_len, &byte_length_str.as_str())
-        })?;
+        let bytes = deserialize_bytes(deserializer)?;
         MultisigPublicKey::from_bytes(&bytes)
             .map_err(|err| SerdeError::custom(format!("{err:?}")))
     }
@@ -77,8 +130,7 @@ impl Serialize for Signature {
         &self,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        let s = bs58::encode(self.to_bytes()).into_string();
-        serializer.serialize_str(&s)
+        serialize_bytes(&self.to_bytes(), serializer)
     }
 }
 
@@ -86,14 +138,7 @@ impl<'de> Deserialize<'de> for Signature {
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
     ) -> Result<Self, D::Error> {
-        let s = String::deserialize(deserializer)?;
-        let decoded =
-            bs58::decode(&s).into_vec().map_err(SerdeError::custom)?;
-        let decoded_len = decoded.len();
-        let byte_length_str = format!("{}", Self::SIZE);
-        let bytes: [u8; Self::SIZE] = decoded.try_into().map_err(|_| {
-            SerdeError::invalid_length(decoded_len, &byte_length_str.as_str())
-        })?;
+        let bytes = deserialize_bytes(deserializer)?;
         Signature::from_bytes(&bytes)
             .map_err(|err| SerdeError::custom(format!("{err:?}")))
     }
@@ -104,8 +149,7 @@ impl Serialize for MultisigSignature {
         &self,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        let s = bs58::encode(self.to_bytes()).into_string();
-        serializer.serialize_str(&s)
+        serialize_bytes(&self.to_bytes(), serializer)
     }
 }
 
@@ -113,26 +157,18 @@ impl<'de> Deserialize<'de> for MultisigSignature {
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
     ) -> Result<Self, D::Error> {
-        let s = String::deserialize(deserializer)?;
-        let decoded =
-            bs58::decode(&s).into_vec().map_err(SerdeError::custom)?;
-        let decoded_len = decoded.len();
-        let byte_length_str = format!("{}", Self::SIZE);
-        let bytes: [u8; Self::SIZE] = decoded.try_into().map_err(|_| {
-            SerdeError::invalid_length(decoded_len, &byte_length_str.as_str())
-        })?;
+        let bytes = deserialize_bytes(deserializer)?;
         MultisigSignature::from_bytes(&bytes)
             .map_err(|err| SerdeError::custom(format!("{err:?}")))
     }
 }
 
-impl Serialize for SecretKey {
+impl Serialize for ExposedSecretKey<'_> {
     fn serialize<S: Serializer>(
         &self,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        let s = bs58::encode(self.to_bytes()).into_string();
-        serializer.serialize_str(&s)
+        serialize_bytes(&self.to_bytes(), serializer)
     }
 }
 
@@ -140,15 +176,27 @@ impl<'de> Deserialize<'de> for SecretKey {
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
     ) -> Result<Self, D::Error> {
-        let s = String::deserialize(deserializer)?;
-        let decoded =
-            bs58::decode(&s).into_vec().map_err(SerdeError::custom)?;
-        let decoded_len = decoded.len();
-        let byte_length_str = format!("{}", Self::SIZE);
-        let bytes: [u8; Self::SIZE] = decoded.try_into().map_err(|_| {
-            SerdeError::invalid_length(decoded_len, &byte_length_str.as_str())
-        })?;
+        let bytes = deserialize_bytes(deserializer)?;
         SecretKey::from_bytes(&bytes)
             .map_err(|err| SerdeError::custom(format!("{err:?}")))
     }
 }
+
+impl Serialize for ExposedKeypair<'_> {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.to_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Keypair {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let bytes = deserialize_bytes(deserializer)?;
+        Keypair::from_bytes(&bytes)
+            .map_err(|err| SerdeError::custom(format!("{err:?}")))
+    }
+}
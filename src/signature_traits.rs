@@ -0,0 +1,153 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Implementations of the RustCrypto [`signature`] traits.
+//!
+//! Digest based signing signs the 32-byte output of the digest under its own
+//! domain separation tag, so a [`DigestSigner`] signature never verifies as a
+//! plain [`Signer`] signature over the same 32 bytes, and vice versa.
+
+use dusk_bytes::{DeserializableSlice, Serializable};
+use signature::digest::Digest;
+use signature::digest::consts::U32;
+use signature::{
+    DigestSigner, DigestVerifier, Error as SignatureError, SignatureEncoding,
+    Signer, Verifier,
+};
+
+use crate::{
+    Error, Keypair, MultisigPublicKey, MultisigSignature, PublicKey, SecretKey,
+    Signature,
+};
+
+impl Signer<Signature> for SecretKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, SignatureError> {
+        Ok(self.sign(msg))
+    }
+}
+
+impl<D> DigestSigner<D, Signature> for SecretKey
+where
+    D: Digest<OutputSize = U32>,
+{
+    fn try_sign_digest(&self, digest: D) -> Result<Signature, SignatureError> {
+        Ok(self.sign_prehashed(&digest.finalize().into()))
+    }
+}
+
+impl signature::Keypair for SecretKey {
+    type VerifyingKey = PublicKey;
+
+    fn verifying_key(&self) -> PublicKey {
+        PublicKey::from(self)
+    }
+}
+
+impl Signer<Signature> for Keypair {
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature, SignatureError> {
+        Ok(Keypair::sign(self, msg))
+    }
+}
+
+impl Signer<MultisigSignature> for Keypair {
+    fn try_sign(
+        &self,
+        msg: &[u8],
+    ) -> Result<MultisigSignature, SignatureError> {
+        Ok(self.sign_multisig(msg))
+    }
+}
+
+impl<D> DigestSigner<D, Signature> for Keypair
+where
+    D: Digest<OutputSize = U32>,
+{
+    fn try_sign_digest(&self, digest: D) -> Result<Signature, SignatureError> {
+        self.secret_key().try_sign_digest(digest)
+    }
+}
+
+impl AsRef<PublicKey> for Keypair {
+    fn as_ref(&self) -> &PublicKey {
+        self.public_key()
+    }
+}
+
+impl signature::KeypairRef for Keypair {
+    type VerifyingKey = PublicKey;
+}
+
+impl Verifier<Signature> for PublicKey {
+    fn verify(
+        &self,
+        msg: &[u8],
+        signature: &Signature,
+    ) -> Result<(), SignatureError> {
+        PublicKey::verify(self, signature, msg)
+            .map_err(|_| SignatureError::new())
+    }
+}
+
+impl<D> DigestVerifier<D, Signature> for PublicKey
+where
+    D: Digest<OutputSize = U32>,
+{
+    fn verify_digest(
+        &self,
+        digest: D,
+        signature: &Signature,
+    ) -> Result<(), SignatureError> {
+        self.verify_prehashed(signature, &digest.finalize().into())
+            .map_err(|_| SignatureError::new())
+    }
+}
+
+impl Verifier<MultisigSignature> for MultisigPublicKey {
+    fn verify(
+        &self,
+        msg: &[u8],
+        signature: &MultisigSignature,
+    ) -> Result<(), SignatureError> {
+        MultisigPublicKey::verify(self, signature, msg)
+            .map_err(|_| SignatureError::new())
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_slice(bytes)
+    }
+}
+
+impl From<Signature> for [u8; Signature::SIZE] {
+    fn from(sig: Signature) -> Self {
+        Serializable::to_bytes(&sig)
+    }
+}
+
+impl SignatureEncoding for Signature {
+    type Repr = [u8; Signature::SIZE];
+}
+
+impl TryFrom<&[u8]> for MultisigSignature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_slice(bytes)
+    }
+}
+
+impl From<MultisigSignature> for [u8; MultisigSignature::SIZE] {
+    fn from(sig: MultisigSignature) -> Self {
+        Serializable::to_bytes(&sig)
+    }
+}
+
+impl SignatureEncoding for MultisigSignature {
+    type Repr = [u8; MultisigSignature::SIZE];
+}
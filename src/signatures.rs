@@ -5,9 +5,12 @@
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
 use crate::Error;
+use crate::batch::decode_batch;
 
 use dusk_bls12_381::{G1Affine, G1Projective};
-use dusk_bytes::Serializable;
+use dusk_bytes::{Error as DuskBytesError, Serializable};
+
+use alloc::vec::Vec;
 
 #[cfg(feature = "rkyv-impl")]
 use rkyv::{Archive, Deserialize, Serialize};
@@ -33,6 +36,45 @@ impl Signature {
     pub fn is_valid(&self) -> bool {
         is_valid(&self.0)
     }
+
+    /// Size of the uncompressed encoding, see [`Signature::to_uncompressed`].
+    pub const UNCOMPRESSED_SIZE: usize = 96;
+
+    /// Serialize the signature as an uncompressed G1 point, in the ZCash
+    /// serialization format.
+    pub fn to_uncompressed(&self) -> [u8; Self::UNCOMPRESSED_SIZE] {
+        self.0.to_uncompressed()
+    }
+
+    /// Deserialize a signature from its uncompressed encoding.
+    ///
+    /// Decoding avoids the square root needed by [`Signature::from_bytes`],
+    /// while still fully validating the point.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the encoding is malformed, or the point is not a valid
+    /// signature according to [`Signature::is_valid`].
+    pub fn from_uncompressed(
+        bytes: &[u8; Self::UNCOMPRESSED_SIZE],
+    ) -> Result<Self, Error> {
+        g1_from_uncompressed(bytes).map(Self)
+    }
+
+    /// Deserialize a list of [`Signature`]s, as produced by `to_bytes`.
+    ///
+    /// Each encoding is decompressed and checked on its own like with
+    /// `from_bytes`, in parallel when the `parallel` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns the index of the first encoding that fails to deserialize,
+    /// together with its error.
+    pub fn from_bytes_batch(
+        bytes: &[[u8; 48]],
+    ) -> Result<Vec<Self>, (usize, Error)> {
+        decode_batch(bytes, Self::from_bytes)
+    }
 }
 
 impl Serializable<48> for Signature {
@@ -43,13 +85,63 @@ impl Serializable<48> for Signature {
     }
 
     fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
-        Ok(Self(G1Affine::from_bytes(bytes)?))
+        g1_from_bytes(bytes).map(Self)
     }
 }
 
 pub(crate) fn is_valid(sig: &G1Affine) -> bool {
-    let is_identity: bool = sig.is_identity().into();
-    sig.is_torsion_free().into() && sig.is_on_curve().into() && !is_identity
+    check(sig).is_ok()
+}
+
+/// Decode a compressed G1 point, reporting why it is invalid.
+///
+/// Like the decoding of `G1Affine`, the identity is accepted.
+pub(crate) fn g1_from_bytes(bytes: &[u8; 48]) -> Result<G1Affine, Error> {
+    let point: G1Affine =
+        Option::from(G1Affine::from_compressed_unchecked(bytes))
+            .ok_or_else(|| decompression_error(bytes[0]))?;
+    if !bool::from(point.is_torsion_free()) {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(point)
+}
+
+/// The error of a compressed point encoding that failed to decompress, given
+/// its flag byte.
+///
+/// A missing compression flag or a malformed identity are encoding errors,
+/// otherwise the encoded x-coordinate is not the one of a point on the curve.
+pub(crate) fn decompression_error(flags: u8) -> Error {
+    const COMPRESSION: u8 = 1 << 7;
+    const INFINITY: u8 = 1 << 6;
+
+    if flags & COMPRESSION == 0 || flags & INFINITY != 0 {
+        DuskBytesError::InvalidData.into()
+    } else {
+        Error::InvalidPoint
+    }
+}
+
+/// Decode an uncompressed G1 point and check it is a valid signature.
+fn g1_from_uncompressed(bytes: &[u8; 96]) -> Result<G1Affine, Error> {
+    let point = Option::from(G1Affine::from_uncompressed_unchecked(bytes))
+        .ok_or(DuskBytesError::InvalidData)?;
+    check(&point)?;
+    Ok(point)
+}
+
+/// Check a signature point, reporting why it is invalid.
+pub(crate) fn check(sig: &G1Affine) -> Result<(), Error> {
+    if !bool::from(sig.is_on_curve()) {
+        return Err(Error::InvalidPoint);
+    }
+    if sig.is_identity().into() {
+        return Err(Error::IdentityPoint);
+    }
+    if !bool::from(sig.is_torsion_free()) {
+        return Err(Error::NotInSubgroup);
+    }
+    Ok(())
 }
 
 /// A BLS signature, in the multi-signature scheme.
@@ -82,6 +174,47 @@ impl MultisigSignature {
     pub fn is_valid(&self) -> bool {
         is_valid(&self.0)
     }
+
+    /// Size of the uncompressed encoding, see
+    /// [`MultisigSignature::to_uncompressed`].
+    pub const UNCOMPRESSED_SIZE: usize = 96;
+
+    /// Serialize the signature as an uncompressed G1 point, in the ZCash
+    /// serialization format.
+    pub fn to_uncompressed(&self) -> [u8; Self::UNCOMPRESSED_SIZE] {
+        self.0.to_uncompressed()
+    }
+
+    /// Deserialize a signature from its uncompressed encoding.
+    ///
+    /// Decoding avoids the square root needed by
+    /// [`MultisigSignature::from_bytes`], while still fully validating the
+    /// point.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the encoding is malformed, or the point is not a valid
+    /// signature according to [`MultisigSignature::is_valid`].
+    pub fn from_uncompressed(
+        bytes: &[u8; Self::UNCOMPRESSED_SIZE],
+    ) -> Result<Self, Error> {
+        g1_from_uncompressed(bytes).map(Self)
+    }
+
+    /// Deserialize a list of [`MultisigSignature`]s, as produced by `to_bytes`.
+    ///
+    /// Each encoding is decompressed and checked on its own like with
+    /// `from_bytes`, in parallel when the `parallel` feature is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns the index of the first encoding that fails to deserialize,
+    /// together with its error.
+    pub fn from_bytes_batch(
+        bytes: &[[u8; 48]],
+    ) -> Result<Vec<Self>, (usize, Error)> {
+        decode_batch(bytes, Self::from_bytes)
+    }
 }
 
 impl Serializable<48> for MultisigSignature {
@@ -92,6 +225,6 @@ impl Serializable<48> for MultisigSignature {
     }
 
     fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
-        Ok(Self(G1Affine::from_bytes(bytes)?))
+        g1_from_bytes(bytes).map(Self)
     }
 }
@@ -0,0 +1,85 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Checksummed text encodings with a type prefix.
+//!
+//! Keys and signatures are displayed as bech32m strings, whose human readable
+//! prefix names the type of the encoded value. Parsing checks both the
+//! checksum and the prefix, so that e.g. an aggregated key can't be mistaken
+//! for a single one.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+use bech32::primitives::decode::CheckedHrpstring;
+use bech32::{Bech32m, Hrp};
+use dusk_bytes::{BadLength, Serializable};
+
+use crate::{
+    Error, ExposedSecretKey, MultisigPublicKey, MultisigSignature, PublicKey,
+    SecretKey, Signature,
+};
+
+const PUBLIC_KEY_HRP: Hrp = Hrp::parse_unchecked("blspk");
+const MULTISIG_PUBLIC_KEY_HRP: Hrp = Hrp::parse_unchecked("blsapk");
+const SIGNATURE_HRP: Hrp = Hrp::parse_unchecked("blssig");
+const MULTISIG_SIGNATURE_HRP: Hrp = Hrp::parse_unchecked("blsmsig");
+const SECRET_KEY_HRP: Hrp = Hrp::parse_unchecked("blssk");
+
+fn encode(f: &mut fmt::Formatter, hrp: Hrp, bytes: &[u8]) -> fmt::Result {
+    bech32::encode_lower_to_fmt::<Bech32m, _>(f, hrp, bytes)
+        .map_err(|_| fmt::Error)
+}
+
+fn decode<const N: usize>(hrp: Hrp, s: &str) -> Result<[u8; N], Error> {
+    let checked = CheckedHrpstring::new::<Bech32m>(s)
+        .map_err(|_| Error::InvalidEncoding)?;
+    if checked.hrp() != hrp {
+        return Err(Error::WrongPrefix);
+    }
+
+    let bytes: Vec<u8> = checked.byte_iter().collect();
+    let len = bytes.len();
+    bytes.try_into().map_err(|_| Error::bad_length(len, N))
+}
+
+macro_rules! impl_text_encoding {
+    ($ty:ty, $hrp:expr) => {
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                encode(f, $hrp, &self.to_bytes())
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self, Error> {
+                Self::from_bytes(&decode($hrp, s)?).map_err(Error::from)
+            }
+        }
+    };
+}
+
+impl_text_encoding!(PublicKey, PUBLIC_KEY_HRP);
+impl_text_encoding!(MultisigPublicKey, MULTISIG_PUBLIC_KEY_HRP);
+impl_text_encoding!(Signature, SIGNATURE_HRP);
+impl_text_encoding!(MultisigSignature, MULTISIG_SIGNATURE_HRP);
+
+impl fmt::Display for ExposedSecretKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        encode(f, SECRET_KEY_HRP, &self.to_bytes())
+    }
+}
+
+impl FromStr for SecretKey {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Self::from_bytes(&decode(SECRET_KEY_HRP, s)?)
+    }
+}
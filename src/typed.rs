@@ -0,0 +1,272 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Typed, domain-separated messages.
+//!
+//! A [`SignableMessage`] is hashed together with its domain tag and version,
+//! under a DST distinct from the one used for raw byte messages. A signature
+//! over a typed message can therefore neither be replayed as a signature over
+//! another message type, nor as a signature over raw bytes.
+
+use crate::hash::{H0_TYPED_DST, HashedMessage, MessageHasher};
+use crate::{Error, PublicKey, SecretKey, Signature};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use dusk_bls12_381::BlsScalar;
+use dusk_bytes::Serializable;
+
+/// A structured message that can be signed with [`SecretKey::sign_typed`].
+///
+/// Implementations can be written by hand, or with the
+/// [`impl_signable_message`](crate::impl_signable_message) macro for structs
+/// whose fields implement [`EncodeField`].
+pub trait SignableMessage {
+    /// Domain tag identifying the message type, e.g. `b"dusk/vote"`.
+    const DOMAIN: &'static [u8];
+
+    /// Version of the message type, to be bumped whenever its encoding
+    /// changes.
+    const VERSION: u16;
+
+    /// Write the canonical encoding of the message to the encoder.
+    fn encode(&self, encoder: &mut MessageEncoder);
+}
+
+/// Encoder writing the canonical encoding of a [`SignableMessage`] straight
+/// into the message hasher.
+///
+/// Integers are encoded big-endian with their fixed width, booleans as a
+/// single byte, fixed-size arrays as is and variable-length byte strings
+/// prefixed with their length as a `u64`.
+#[derive(Debug)]
+pub struct MessageEncoder {
+    hasher: MessageHasher,
+}
+
+impl MessageEncoder {
+    fn new<M: SignableMessage + ?Sized>() -> Self {
+        let mut encoder = Self {
+            hasher: MessageHasher::with_dst(H0_TYPED_DST),
+        };
+        encoder.write_bytes(M::DOMAIN);
+        encoder.write_u16(M::VERSION);
+        encoder
+    }
+
+    /// Write raw bytes, without a length prefix.
+    pub fn write_raw(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+
+    /// Write a variable-length byte string, prefixed with its length.
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_u64(bytes.len() as u64);
+        self.write_raw(bytes);
+    }
+
+    /// Write a `u8`.
+    pub fn write_u8(&mut self, n: u8) {
+        self.write_raw(&[n]);
+    }
+
+    /// Write a `u16`.
+    pub fn write_u16(&mut self, n: u16) {
+        self.write_raw(&n.to_be_bytes());
+    }
+
+    /// Write a `u32`.
+    pub fn write_u32(&mut self, n: u32) {
+        self.write_raw(&n.to_be_bytes());
+    }
+
+    /// Write a `u64`.
+    pub fn write_u64(&mut self, n: u64) {
+        self.write_raw(&n.to_be_bytes());
+    }
+
+    /// Write a `bool`.
+    pub fn write_bool(&mut self, b: bool) {
+        self.write_u8(b as u8);
+    }
+
+    /// Write a field implementing [`EncodeField`].
+    pub fn write<F: EncodeField + ?Sized>(&mut self, field: &F) {
+        field.encode_field(self);
+    }
+}
+
+/// Hash a [`SignableMessage`] to G1.
+pub(crate) fn hash_typed<M>(msg: &M) -> HashedMessage
+where
+    M: SignableMessage + ?Sized,
+{
+    let mut encoder = MessageEncoder::new::<M>();
+    msg.encode(&mut encoder);
+    encoder.hasher.finalize()
+}
+
+/// A field of a [`SignableMessage`] with a canonical encoding.
+pub trait EncodeField {
+    /// Write the canonical encoding of the field to the encoder.
+    fn encode_field(&self, encoder: &mut MessageEncoder);
+}
+
+impl<F: EncodeField + ?Sized> EncodeField for &F {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        (**self).encode_field(encoder);
+    }
+}
+
+impl EncodeField for u8 {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_u8(*self);
+    }
+}
+
+impl EncodeField for u16 {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_u16(*self);
+    }
+}
+
+impl EncodeField for u32 {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_u32(*self);
+    }
+}
+
+impl EncodeField for u64 {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_u64(*self);
+    }
+}
+
+impl EncodeField for bool {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_bool(*self);
+    }
+}
+
+impl<const N: usize> EncodeField for [u8; N] {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_raw(self);
+    }
+}
+
+impl EncodeField for [u8] {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_bytes(self);
+    }
+}
+
+impl EncodeField for Vec<u8> {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_bytes(self);
+    }
+}
+
+impl EncodeField for str {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_bytes(self.as_bytes());
+    }
+}
+
+impl EncodeField for String {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_bytes(self.as_bytes());
+    }
+}
+
+impl<F: EncodeField> EncodeField for Option<F> {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        match self {
+            Some(field) => {
+                encoder.write_bool(true);
+                field.encode_field(encoder);
+            }
+            None => encoder.write_bool(false),
+        }
+    }
+}
+
+impl EncodeField for BlsScalar {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_raw(&self.to_bytes());
+    }
+}
+
+impl EncodeField for PublicKey {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_raw(&self.to_bytes());
+    }
+}
+
+impl EncodeField for Signature {
+    fn encode_field(&self, encoder: &mut MessageEncoder) {
+        encoder.write_raw(&self.to_bytes());
+    }
+}
+
+impl SecretKey {
+    /// Sign a typed, domain-separated [`SignableMessage`].
+    pub fn sign_typed<M>(&self, msg: &M) -> Signature
+    where
+        M: SignableMessage + ?Sized,
+    {
+        self.sign_hashed(&hash_typed(msg))
+    }
+}
+
+impl PublicKey {
+    /// Verify a [`Signature`] over a typed, domain-separated
+    /// [`SignableMessage`].
+    pub fn verify_typed<M>(&self, sig: &Signature, msg: &M) -> Result<(), Error>
+    where
+        M: SignableMessage + ?Sized,
+    {
+        self.verify_hashed(sig, &hash_typed(msg))
+    }
+}
+
+/// Implement [`SignableMessage`] for a struct by encoding the listed fields in
+/// order.
+///
+/// Every listed field must implement [`EncodeField`].
+///
+/// ## Example
+///
+/// ```
+/// use bls12_381_bls::{PublicKey, SecretKey, impl_signable_message};
+///
+/// struct Vote {
+///     round: u64,
+///     step: u8,
+///     block_hash: [u8; 32],
+/// }
+///
+/// impl_signable_message!(Vote, b"dusk/vote", 1, [round, step, block_hash]);
+///
+/// let sk = SecretKey::from(dusk_bls12_381::BlsScalar::from(42));
+/// let vote = Vote { round: 7, step: 1, block_hash: [0; 32] };
+///
+/// let sig = sk.sign_typed(&vote);
+/// assert!(PublicKey::from(&sk).verify_typed(&sig, &vote).is_ok());
+/// ```
+#[macro_export]
+macro_rules! impl_signable_message {
+    ($ty:ty, $domain:expr, $version:expr, [$($field:ident),* $(,)?]) => {
+        impl $crate::SignableMessage for $ty {
+            const DOMAIN: &'static [u8] = $domain;
+            const VERSION: u16 = $version;
+
+            fn encode(&self, encoder: &mut $crate::MessageEncoder) {
+                $( encoder.write(&self.$field); )*
+            }
+        }
+    };
+}
@@ -0,0 +1,342 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Signatures tagged with the scheme that produced them.
+//!
+//! Historical data mixes insecure v1 signatures with secure v2 ones. The
+//! versioned types carry a scheme tag byte, so verifiers can dispatch to the
+//! right verification rules without knowing the provenance of the data.
+//!
+//! Verifying v1 signatures requires the `insecure-v1-verify` feature. Without
+//! it, v1 signatures can still be decoded but are never accepted.
+
+#[cfg(feature = "insecure-v1-verify")]
+use crate::legacy;
+use crate::{
+    Error, MultisigPublicKey, MultisigSignature, PublicKey, Signature,
+};
+
+use dusk_bytes::{Error as DuskBytesError, Serializable};
+
+/// The signature scheme a versioned signature was produced with.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum SignatureScheme {
+    /// The insecure v1 scheme, see the `legacy` module.
+    V1Insecure = 1,
+    /// The secure v2 scheme, see [`PublicKey::verify`].
+    V2 = 2,
+}
+
+impl SignatureScheme {
+    /// Returns the tag byte of the scheme.
+    pub const fn tag(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the scheme identified by the tag byte, if any.
+    pub const fn from_tag(tag: u8) -> Option<Self> {
+        match tag {
+            1 => Some(Self::V1Insecure),
+            2 => Some(Self::V2),
+            _ => None,
+        }
+    }
+}
+
+/// Policy deciding which [`SignatureScheme`]s a verifier accepts.
+///
+/// The policy is checked against the position of the verified data, e.g. a
+/// block height, so that a single policy can refuse v1 signatures from a
+/// cutoff onwards.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VersionPolicy {
+    v1: V1Rule,
+}
+
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+enum V1Rule {
+    Never,
+    Always,
+    Before(u64),
+}
+
+impl Default for VersionPolicy {
+    /// Only accept secure v2 signatures.
+    fn default() -> Self {
+        Self::SECURE_ONLY
+    }
+}
+
+impl VersionPolicy {
+    /// Accept both insecure v1 and secure v2 signatures.
+    pub const ANY: Self = Self { v1: V1Rule::Always };
+
+    /// Only accept secure v2 signatures.
+    pub const SECURE_ONLY: Self = Self { v1: V1Rule::Never };
+
+    /// Accept insecure v1 signatures only for data at a position strictly
+    /// before the `cutoff`.
+    pub const fn v1_cutoff(cutoff: u64) -> Self {
+        Self {
+            v1: V1Rule::Before(cutoff),
+        }
+    }
+
+    /// Returns true if the policy accepts the given scheme for data at
+    /// `position`.
+    pub const fn accepts(
+        &self,
+        scheme: SignatureScheme,
+        position: u64,
+    ) -> bool {
+        match scheme {
+            SignatureScheme::V1Insecure => match self.v1 {
+                V1Rule::Never => false,
+                V1Rule::Always => true,
+                V1Rule::Before(cutoff) => position < cutoff,
+            },
+            SignatureScheme::V2 => true,
+        }
+    }
+
+    fn check(
+        &self,
+        scheme: SignatureScheme,
+        position: u64,
+    ) -> Result<(), Error> {
+        if self.accepts(scheme, position) {
+            Ok(())
+        } else {
+            Err(Error::SchemeNotAccepted)
+        }
+    }
+}
+
+/// A [`Signature`] tagged with the [`SignatureScheme`] that produced it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct VersionedSignature {
+    scheme: SignatureScheme,
+    sig: Signature,
+}
+
+impl VersionedSignature {
+    /// Returns the scheme of the signature.
+    pub const fn scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+
+    /// Returns the untagged signature, if it is a secure v2 signature.
+    pub fn signature(&self) -> Option<&Signature> {
+        match self.scheme {
+            SignatureScheme::V2 => Some(&self.sig),
+            SignatureScheme::V1Insecure => None,
+        }
+    }
+
+    /// Returns the untagged signature, if it is an insecure v1 signature.
+    #[cfg(feature = "insecure-v1-verify")]
+    pub fn legacy_signature(&self) -> Option<legacy::Signature> {
+        match self.scheme {
+            SignatureScheme::V1Insecure => Some(legacy::Signature(self.sig.0)),
+            SignatureScheme::V2 => None,
+        }
+    }
+}
+
+impl From<Signature> for VersionedSignature {
+    /// Tag a [`Signature`] produced by the default, secure v2 scheme.
+    fn from(sig: Signature) -> Self {
+        Self {
+            scheme: SignatureScheme::V2,
+            sig,
+        }
+    }
+}
+
+#[cfg(feature = "insecure-v1-verify")]
+impl From<legacy::Signature> for VersionedSignature {
+    /// Tag a [`legacy::Signature`] produced by the insecure v1 scheme.
+    fn from(sig: legacy::Signature) -> Self {
+        Self {
+            scheme: SignatureScheme::V1Insecure,
+            sig: Signature(sig.0),
+        }
+    }
+}
+
+impl Serializable<49> for VersionedSignature {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[0] = self.scheme.tag();
+        bytes[1..].copy_from_slice(&self.sig.to_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        let scheme = SignatureScheme::from_tag(bytes[0])
+            .ok_or(DuskBytesError::InvalidData)?;
+        let mut sig_bytes = [0u8; Signature::SIZE];
+        sig_bytes.copy_from_slice(&bytes[1..]);
+        let sig = Signature::from_bytes(&sig_bytes)?;
+        Ok(Self { scheme, sig })
+    }
+}
+
+/// A [`MultisigSignature`] tagged with the [`SignatureScheme`] that produced
+/// it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct VersionedMultisigSignature {
+    scheme: SignatureScheme,
+    sig: MultisigSignature,
+}
+
+impl VersionedMultisigSignature {
+    /// Returns the scheme of the signature.
+    pub const fn scheme(&self) -> SignatureScheme {
+        self.scheme
+    }
+
+    /// Returns the untagged signature, if it is a secure v2 signature.
+    pub fn signature(&self) -> Option<&MultisigSignature> {
+        match self.scheme {
+            SignatureScheme::V2 => Some(&self.sig),
+            SignatureScheme::V1Insecure => None,
+        }
+    }
+
+    /// Returns the untagged signature, if it is an insecure v1 signature.
+    #[cfg(feature = "insecure-v1-verify")]
+    pub fn legacy_signature(&self) -> Option<legacy::MultisigSignature> {
+        match self.scheme {
+            SignatureScheme::V1Insecure => {
+                Some(legacy::MultisigSignature(self.sig.0))
+            }
+            SignatureScheme::V2 => None,
+        }
+    }
+
+    /// Verify the signature against the keys of its signers, dispatching on
+    /// the scheme tag.
+    ///
+    /// Unlike single signatures, the aggregated public key depends on the
+    /// scheme, so the keys are aggregated with the coefficients of the tagged
+    /// scheme before verification.
+    ///
+    /// The policy is checked for data at `position`, see
+    /// [`VersionPolicy::accepts`]. Returns the scheme the signature was
+    /// validated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SchemeNotAccepted`] if the policy refuses the tagged
+    /// scheme, or if it is the v1 scheme and the `insecure-v1-verify` feature
+    /// is disabled. Otherwise returns any error of the aggregation and
+    /// verification.
+    pub fn verify_any(
+        &self,
+        pks: &[PublicKey],
+        msg: &[u8],
+        policy: &VersionPolicy,
+        position: u64,
+    ) -> Result<SignatureScheme, Error> {
+        policy.check(self.scheme, position)?;
+        match self.scheme {
+            #[cfg(feature = "insecure-v1-verify")]
+            SignatureScheme::V1Insecure => {
+                let pks: alloc::vec::Vec<_> =
+                    pks.iter().map(legacy::PublicKey::from).collect();
+                legacy::MultisigPublicKey::aggregate(&pks)?
+                    .verify(&legacy::MultisigSignature(self.sig.0), msg)?
+            }
+            #[cfg(not(feature = "insecure-v1-verify"))]
+            SignatureScheme::V1Insecure => {
+                return Err(Error::SchemeNotAccepted);
+            }
+            SignatureScheme::V2 => {
+                MultisigPublicKey::aggregate(pks)?.verify(&self.sig, msg)?
+            }
+        }
+        Ok(self.scheme)
+    }
+}
+
+impl From<MultisigSignature> for VersionedMultisigSignature {
+    /// Tag a [`MultisigSignature`] produced by the default, secure v2 scheme.
+    fn from(sig: MultisigSignature) -> Self {
+        Self {
+            scheme: SignatureScheme::V2,
+            sig,
+        }
+    }
+}
+
+#[cfg(feature = "insecure-v1-verify")]
+impl From<legacy::MultisigSignature> for VersionedMultisigSignature {
+    /// Tag a [`legacy::MultisigSignature`] produced by the insecure v1 scheme.
+    fn from(sig: legacy::MultisigSignature) -> Self {
+        Self {
+            scheme: SignatureScheme::V1Insecure,
+            sig: MultisigSignature(sig.0),
+        }
+    }
+}
+
+impl Serializable<49> for VersionedMultisigSignature {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[0] = self.scheme.tag();
+        bytes[1..].copy_from_slice(&self.sig.to_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        let scheme = SignatureScheme::from_tag(bytes[0])
+            .ok_or(DuskBytesError::InvalidData)?;
+        let mut sig_bytes = [0u8; MultisigSignature::SIZE];
+        sig_bytes.copy_from_slice(&bytes[1..]);
+        let sig = MultisigSignature::from_bytes(&sig_bytes)?;
+        Ok(Self { scheme, sig })
+    }
+}
+
+impl PublicKey {
+    /// Verify a [`VersionedSignature`], dispatching on its scheme tag.
+    ///
+    /// The policy is checked for data at `position`, see
+    /// [`VersionPolicy::accepts`]. Returns the scheme the signature was
+    /// validated with.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SchemeNotAccepted`] if the policy refuses the tagged
+    /// scheme, or if it is the v1 scheme and the `insecure-v1-verify` feature
+    /// is disabled. Otherwise returns any error of the verification.
+    pub fn verify_any(
+        &self,
+        sig: &VersionedSignature,
+        msg: &[u8],
+        policy: &VersionPolicy,
+        position: u64,
+    ) -> Result<SignatureScheme, Error> {
+        policy.check(sig.scheme, position)?;
+        match sig.scheme {
+            #[cfg(feature = "insecure-v1-verify")]
+            SignatureScheme::V1Insecure => legacy::PublicKey::from(self)
+                .verify(&legacy::Signature(sig.sig.0), msg)?,
+            #[cfg(not(feature = "insecure-v1-verify"))]
+            SignatureScheme::V1Insecure => {
+                return Err(Error::SchemeNotAccepted);
+            }
+            SignatureScheme::V2 => self.verify(&sig.sig, msg)?,
+        }
+        Ok(sig.scheme)
+    }
+}
@@ -0,0 +1,119 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+//! Verifiable random function based on BLS signatures.
+//!
+//! The proof for an input is the BLS signature on the input, hashed to G1
+//! under a dedicated DST so that proofs can't be mistaken for signatures.
+//! Since a BLS signature is unique for a given key and input, so is the
+//! proof, and the output is derived by hashing the proof.
+
+use dusk_bls12_381::G1Affine;
+use dusk_bytes::Serializable;
+use sha2::{Digest, Sha256};
+
+use crate::hash::{VRF_DST, hash_to_g1};
+use crate::keys::public::verify_point;
+use crate::signatures::{g1_from_bytes, is_valid};
+use crate::{Error, PublicKey, SecretKey};
+
+#[cfg(feature = "rkyv-impl")]
+use rkyv::{Archive, Deserialize, Serialize};
+
+// Domain separation of the output hash.
+const OUTPUT_DST: &[u8] = b"BLS_VRF_OUTPUT_SHA-256_DUSK_V1";
+
+/// The proof of a [`VrfOutput`], checked with [`PublicKey::vrf_verify`].
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct VrfProof(G1Affine);
+
+impl VrfProof {
+    /// Derive the output proven by the proof.
+    ///
+    /// The output is only meaningful once the proof is verified with
+    /// [`PublicKey::vrf_verify`], which returns it as well.
+    pub fn output(&self) -> VrfOutput {
+        let mut hasher = Sha256::new();
+        hasher.update(OUTPUT_DST);
+        hasher.update(self.0.to_bytes());
+        VrfOutput(hasher.finalize().into())
+    }
+
+    /// Returns true if the inner point is valid, see
+    /// [`Signature::is_valid`](crate::Signature::is_valid).
+    pub fn is_valid(&self) -> bool {
+        is_valid(&self.0)
+    }
+}
+
+impl Serializable<48> for VrfProof {
+    type Error = Error;
+
+    fn to_bytes(&self) -> [u8; Self::SIZE] {
+        self.0.to_bytes()
+    }
+
+    fn from_bytes(bytes: &[u8; Self::SIZE]) -> Result<Self, Error> {
+        Ok(Self(g1_from_bytes(bytes)?))
+    }
+}
+
+/// The pseudorandom output of the VRF.
+#[derive(Default, Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "rkyv-impl",
+    derive(Archive, Deserialize, Serialize),
+    archive_attr(derive(bytecheck::CheckBytes))
+)]
+pub struct VrfOutput([u8; 32]);
+
+impl VrfOutput {
+    /// The output bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl From<VrfOutput> for [u8; 32] {
+    fn from(output: VrfOutput) -> Self {
+        output.0
+    }
+}
+
+fn h_vrf(input: &[u8]) -> G1Affine {
+    hash_to_g1(input, VRF_DST)
+}
+
+impl SecretKey {
+    /// Evaluate the VRF on `input`, returning the output together with its
+    /// proof.
+    pub fn vrf_prove(&self, input: &[u8]) -> (VrfOutput, VrfProof) {
+        let proof = VrfProof((h_vrf(input) * self.0).into());
+        (proof.output(), proof)
+    }
+}
+
+impl PublicKey {
+    /// Verify a VRF proof on `input`, returning the proven output.
+    ///
+    /// # Errors
+    ///
+    /// Errors with [`Error::InvalidSignature`] if the proof doesn't verify,
+    /// or with the point errors of [`PublicKey::verify`].
+    pub fn vrf_verify(
+        &self,
+        input: &[u8],
+        proof: &VrfProof,
+    ) -> Result<VrfOutput, Error> {
+        verify_point(&self.0, &proof.0, &h_vrf(input))?;
+        Ok(proof.output())
+    }
+}
@@ -0,0 +1,96 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "rkyv-impl")]
+
+use bls12_381_bls::{
+    Error, MultisigPublicKey, MultisigSignature, PublicKey, SecretKey,
+    Signature,
+};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rkyv::{Archive, Serialize, check_archived_root};
+
+#[derive(Archive, Serialize)]
+#[archive_attr(derive(bytecheck::CheckBytes))]
+struct CallData {
+    pks: [PublicKey; 3],
+    apk: MultisigPublicKey,
+    sig: Signature,
+    ms_sigs: [MultisigSignature; 3],
+}
+
+#[test]
+fn verify_archived() {
+    let mut rng = StdRng::seed_from_u64(0xa7c1);
+    let msg = b"a message";
+
+    let sks = [(); 3].map(|_| SecretKey::random(&mut rng));
+    let pks = [0, 1, 2].map(|i| PublicKey::from(&sks[i]));
+    let ms_sigs = [0, 1, 2].map(|i| sks[i].sign_multisig(&pks[i], msg));
+    let apk = MultisigPublicKey::aggregate(&pks).unwrap();
+
+    let call_data = CallData {
+        pks,
+        apk,
+        sig: sks[0].sign(msg),
+        ms_sigs,
+    };
+    let bytes = rkyv::to_bytes::<_, 1024>(&call_data).unwrap();
+    let archived = check_archived_root::<CallData>(&bytes).unwrap();
+
+    assert!(archived.pks[0].verify(&archived.sig, msg).is_ok());
+    assert_eq!(
+        archived.pks[1].verify(&archived.sig, msg),
+        Err(Error::InvalidSignature)
+    );
+
+    assert_eq!(
+        MultisigPublicKey::aggregate_archived(&archived.pks),
+        Ok(apk)
+    );
+
+    let ms_sig = ms_sigs[0].aggregate_archived(&archived.ms_sigs[1..]);
+    assert_eq!(ms_sig, ms_sigs[0].aggregate(&ms_sigs[1..]));
+    assert!(apk.verify(&ms_sig, msg).is_ok());
+
+    let ms_sig_bytes = rkyv::to_bytes::<_, 64>(&ms_sig).unwrap();
+    let archived_ms_sig =
+        check_archived_root::<MultisigSignature>(&ms_sig_bytes).unwrap();
+    assert!(archived.apk.verify(archived_ms_sig, msg).is_ok());
+    assert_eq!(
+        archived.apk.verify(archived_ms_sig, b"another message"),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn aggregate_archived_checks_keys() {
+    let mut rng = StdRng::seed_from_u64(0xa7c1);
+    let pk = PublicKey::from(&SecretKey::random(&mut rng));
+    let identity = PublicKey::default();
+
+    let bytes = rkyv::to_bytes::<_, 1024>(&[pk, identity, pk]).unwrap();
+    let archived = check_archived_root::<[PublicKey; 3]>(&bytes).unwrap();
+    assert_eq!(
+        MultisigPublicKey::aggregate_archived(&archived[..]),
+        Err(Error::InvalidKeyAt { index: 1 })
+    );
+    let bytes = rkyv::to_bytes::<_, 1024>(&[identity, pk, pk]).unwrap();
+    let archived = check_archived_root::<[PublicKey; 3]>(&bytes).unwrap();
+    assert_eq!(
+        MultisigPublicKey::aggregate_archived(&archived[1..]),
+        MultisigPublicKey::aggregate(&[pk, pk])
+    );
+    assert_eq!(
+        MultisigPublicKey::aggregate_archived_distinct(&archived[1..]),
+        Err(Error::DuplicateSigner { index: 1 })
+    );
+    assert_eq!(
+        MultisigPublicKey::aggregate_archived(&[]),
+        Err(Error::NoKeysProvided)
+    );
+}
@@ -0,0 +1,96 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use bls12_381_bls::beacon::{DRAND_G1_DST, randomness, verify_unchained};
+use bls12_381_bls::{Error, PublicKey, SecretKey, Signature, hash_to_g1};
+use dusk_bls12_381::{BlsScalar, G1Affine};
+use dusk_bytes::Serializable;
+use ff::Field;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use sha2_v010::{Digest, Sha256};
+
+// Group public key of drand quicknet.
+const QUICKNET_PK: &str = "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a";
+
+// Round 1000 of quicknet.
+const ROUND: u64 = 1000;
+const SIGNATURE: &str = "b44679b9a59af2ec876b1a6b1ad52ea9b1615fc3982b19576350f93447cb1125e342b73a8dd2bacbe47e4b6b63ed5e39";
+const RANDOMNESS: &str =
+    "fe290beca10872ef2fb164d2aa4442de4566183ec51c56ff3cd603d930e54fdd";
+
+fn quicknet() -> (PublicKey, Signature) {
+    let pk = hex::decode(QUICKNET_PK).unwrap();
+    let sig = hex::decode(SIGNATURE).unwrap();
+    (
+        PublicKey::from_bytes(&pk.try_into().unwrap()).unwrap(),
+        Signature::from_bytes(&sig.try_into().unwrap()).unwrap(),
+    )
+}
+
+/// Sign a round the way a drand node with secret `sk` does.
+fn sign_round(sk: &BlsScalar, message: &[u8]) -> Signature {
+    let h = hash_to_g1(&Sha256::digest(message), DRAND_G1_DST);
+    let sig = G1Affine::from(h * sk);
+    Signature::from_bytes(&sig.to_bytes()).unwrap()
+}
+
+#[test]
+fn quicknet_round() {
+    let (pk, sig) = quicknet();
+
+    assert!(verify_unchained(&pk, ROUND, &sig).is_ok());
+    assert_eq!(hex::encode(randomness(&sig)), RANDOMNESS);
+
+    assert_eq!(
+        verify_unchained(&pk, ROUND + 1, &sig),
+        Err(Error::InvalidSignature)
+    );
+    // Beacons aren't plain signatures on the round.
+    assert_eq!(
+        pk.verify(&sig, &ROUND.to_be_bytes()),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn quicknet_rejects_wrong_rounds() {
+    let (pk, sig) = quicknet();
+
+    for round in [0, 1, ROUND - 1, ROUND + 1, u64::MAX] {
+        assert_eq!(
+            verify_unchained(&pk, round, &sig),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    let mut rng = StdRng::seed_from_u64(0xd7a4d);
+    let other = PublicKey::from(&SecretKey::random(&mut rng));
+    assert_eq!(
+        verify_unchained(&other, ROUND, &sig),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn unchained_rounds() {
+    let mut rng = StdRng::seed_from_u64(0xd7a4d);
+    let sk = BlsScalar::random(&mut rng);
+    let pk = PublicKey::from(&SecretKey::from(sk));
+
+    let sig = sign_round(&sk, &7u64.to_be_bytes());
+    assert!(verify_unchained(&pk, 7, &sig).is_ok());
+
+    let (quicknet_pk, _) = quicknet();
+    assert_eq!(
+        verify_unchained(&quicknet_pk, 7, &sig),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        verify_unchained(&pk, 7, &Signature::default()),
+        Err(Error::IdentityPoint)
+    );
+}
@@ -0,0 +1,138 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use bls12_381_bls::{
+    BlindSignature, BlindedMessage, BlindingFactor, Error, PublicKey, SecretKey,
+};
+use dusk_bls12_381::{G1Affine, G2Affine, pairing};
+use dusk_bytes::Serializable;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+const MSG: &[u8] = b"voucher 1337";
+
+#[test]
+fn blind_sign_unblind() {
+    let mut rng = StdRng::seed_from_u64(0xb11d);
+    let sk = SecretKey::random(&mut rng);
+    let pk = sk.blind_public_key();
+
+    let factor = BlindingFactor::random(&mut rng);
+    let blinded = factor.blind(MSG);
+    assert!(blinded.is_valid());
+
+    let blind_sig = sk.sign_blinded(&blinded).unwrap();
+    let sig = factor.unblind(&pk, MSG, &blind_sig).unwrap();
+
+    // The unblinded signature is a plain signature on the message, under the
+    // blind public key only.
+    assert!(pk.verify(&sig, MSG).is_ok());
+    assert_eq!(
+        PublicKey::from(&sk).verify(&sig, MSG),
+        Err(Error::InvalidSignature)
+    );
+    assert_ne!(pk, PublicKey::from(&sk));
+}
+
+#[test]
+fn blind_signing_is_domain_separated() {
+    let mut rng = StdRng::seed_from_u64(0xb11d);
+    let sk = SecretKey::random(&mut rng);
+
+    // Any point the requester chooses is multiplied by the blind signing key,
+    // never by the secret key itself.
+    let g1 = G1Affine::generator();
+    let blinded = BlindedMessage::from_bytes(&g1.to_bytes()).unwrap();
+    let signed = sk.sign_blinded(&blinded).unwrap().to_bytes();
+    let signed = G1Affine::from_bytes(&signed).unwrap();
+
+    let g2_point =
+        |pk: PublicKey| G2Affine::from_bytes(&pk.to_bytes()).unwrap();
+    let lhs = pairing(&signed, &G2Affine::generator());
+    assert_eq!(lhs, pairing(&g1, &g2_point(sk.blind_public_key())));
+    assert_ne!(lhs, pairing(&g1, &g2_point(PublicKey::from(&sk))));
+}
+
+#[test]
+fn blinding_hides_the_message() {
+    let mut rng = StdRng::seed_from_u64(0xb11d);
+    let sk = SecretKey::random(&mut rng);
+
+    let first = BlindingFactor::random(&mut rng).blind(MSG);
+    let second = BlindingFactor::random(&mut rng).blind(MSG);
+    assert_ne!(first, second);
+    assert_ne!(
+        sk.sign_blinded(&first).unwrap(),
+        sk.sign_blinded(&second).unwrap()
+    );
+    assert_ne!(first.to_bytes(), sk.sign(MSG).to_bytes());
+}
+
+#[test]
+fn unblind_checks_the_signer() {
+    let mut rng = StdRng::seed_from_u64(0xb11d);
+    let sk = SecretKey::random(&mut rng);
+    let pk = sk.blind_public_key();
+    let other = SecretKey::random(&mut rng);
+
+    let factor = BlindingFactor::random(&mut rng);
+    let blinded = factor.blind(MSG);
+
+    let blind_sig = other.sign_blinded(&blinded).unwrap();
+    assert_eq!(
+        factor.unblind(&pk, MSG, &blind_sig),
+        Err(Error::InvalidSignature)
+    );
+
+    let blind_sig = sk.sign_blinded(&blinded).unwrap();
+    assert_eq!(
+        factor.unblind(&pk, b"voucher 1338", &blind_sig),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        BlindingFactor::random(&mut rng).unblind(&pk, MSG, &blind_sig),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        factor.unblind(&pk, MSG, &BlindSignature::default()),
+        Err(Error::IdentityPoint)
+    );
+}
+
+#[test]
+fn malformed_requests() {
+    let mut rng = StdRng::seed_from_u64(0xb11d);
+    let sk = SecretKey::random(&mut rng);
+
+    assert!(!BlindedMessage::default().is_valid());
+    assert_eq!(
+        sk.sign_blinded(&BlindedMessage::default()),
+        Err(Error::IdentityPoint)
+    );
+
+    let identity = G1Affine::identity().to_bytes();
+    let blinded = BlindedMessage::from_bytes(&identity).unwrap();
+    assert_eq!(sk.sign_blinded(&blinded), Err(Error::IdentityPoint));
+
+    assert!(BlindedMessage::from_bytes(&[0xff; 48]).is_err());
+}
+
+#[test]
+fn serialization() {
+    let mut rng = StdRng::seed_from_u64(0xb11d);
+    let sk = SecretKey::random(&mut rng);
+    let factor = BlindingFactor::random(&mut rng);
+    assert_eq!(format!("{factor:?}"), "BlindingFactor(<redacted>)");
+
+    let blinded = factor.blind(MSG);
+    assert_eq!(BlindedMessage::from_bytes(&blinded.to_bytes()), Ok(blinded));
+
+    let blind_sig = sk.sign_blinded(&blinded).unwrap();
+    assert_eq!(
+        BlindSignature::from_bytes(&blind_sig.to_bytes()),
+        Ok(blind_sig)
+    );
+}
@@ -0,0 +1,99 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "child-keys")]
+
+use bls12_381_bls::{MultisigPublicKey, PublicKey, SecretKey, Signature};
+use dusk_bls12_381::{BlsScalar, G1Affine, G1Projective};
+use dusk_bytes::Serializable;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+const CHAIN_CODE: [u8; 32] = [0xcc; 32];
+
+fn point(sig: &Signature) -> G1Projective {
+    G1Affine::from_bytes(&sig.to_bytes()).unwrap().into()
+}
+
+#[test]
+fn derived_keypairs_match() {
+    let mut rng = StdRng::seed_from_u64(0xc41d);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
+
+    for index in [0, 1, u32::MAX] {
+        let child_sk = sk.derive_child(&CHAIN_CODE, index);
+        let child_pk = pk.derive_child(&CHAIN_CODE, index);
+        assert_eq!(PublicKey::from(&child_sk), child_pk);
+        assert!(child_pk.verify(&child_sk.sign(msg), msg).is_ok());
+        assert_ne!(child_pk, pk);
+    }
+
+    assert_ne!(
+        pk.derive_child(&CHAIN_CODE, 0),
+        pk.derive_child(&CHAIN_CODE, 1)
+    );
+    assert_ne!(
+        pk.derive_child(&CHAIN_CODE, 0),
+        pk.derive_child(&[0; 32], 0)
+    );
+    assert_eq!(
+        pk.derive_child(&CHAIN_CODE, 0).derive_child(&CHAIN_CODE, 0),
+        PublicKey::from(
+            &sk.derive_child(&CHAIN_CODE, 0).derive_child(&CHAIN_CODE, 0)
+        )
+    );
+}
+
+/// A signature under one child converts to signatures under its siblings
+/// using only public information.
+#[test]
+fn child_signatures_are_related() {
+    let mut rng = StdRng::seed_from_u64(0xc41d);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
+
+    let child = sk.derive_child(&CHAIN_CODE, 0);
+    let sig = child.sign(msg);
+
+    // The signature of the unit key is the hashed message.
+    let h = point(&SecretKey::from(BlsScalar::one()).sign(msg));
+    let t0 = pk.child_tweak(&CHAIN_CODE, 0);
+    let t1 = pk.child_tweak(&CHAIN_CODE, 1);
+
+    let parent_sig = G1Affine::from(point(&sig) - h * t0);
+    let parent_sig = Signature::from_bytes(&parent_sig.to_bytes()).unwrap();
+    assert!(pk.verify(&parent_sig, msg).is_ok());
+
+    let sibling_sig = G1Affine::from(point(&sig) + h * (t1 - t0));
+    let sibling_sig = Signature::from_bytes(&sibling_sig.to_bytes()).unwrap();
+    let sibling = pk.derive_child(&CHAIN_CODE, 1);
+    assert!(sibling.verify(&sibling_sig, msg).is_ok());
+}
+
+/// Siblings aggregate like distinct keys, but a single holder of the parent
+/// key signs for all of them.
+#[test]
+fn aggregated_children_have_one_signer() {
+    let mut rng = StdRng::seed_from_u64(0xc41d);
+    let sk = SecretKey::random(&mut rng);
+    let msg = b"a message";
+
+    let children: Vec<_> =
+        (0..3).map(|i| sk.derive_child(&CHAIN_CODE, i)).collect();
+    let pks: Vec<_> = children.iter().map(PublicKey::from).collect();
+    let apk = MultisigPublicKey::aggregate(&pks).unwrap();
+
+    let sigs: Vec<_> = children
+        .iter()
+        .zip(&pks)
+        .map(|(child, pk)| child.sign_multisig(pk, msg))
+        .collect();
+    let sig = sigs[0].aggregate(&sigs[1..]);
+    assert!(apk.verify(&sig, msg).is_ok());
+}
@@ -0,0 +1,130 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "cose")]
+
+use bls12_381_bls::{COSE_ALGORITHM, Error, PublicKey, SecretKey};
+use coset::{
+    Algorithm, CborSerializable, CoseKey, CoseSign1, CoseSign1Builder,
+    HeaderBuilder, Label, iana,
+};
+use dusk_bytes::BadLength;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn key_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0xc05e);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
+
+    for key in [pk.to_cose_key(), pk.to_cose_key_ec2()] {
+        let key = CoseKey::from_slice(&key.to_vec().unwrap()).unwrap();
+        assert_eq!(PublicKey::from_cose_key(&key), Ok(pk));
+        assert_eq!(SecretKey::from_cose_key(&key), Err(Error::InvalidEncoding));
+    }
+
+    for key in [
+        sk.expose_secret().to_cose_key(),
+        sk.expose_secret().to_cose_key_ec2(),
+    ] {
+        let key = CoseKey::from_slice(&key.to_vec().unwrap()).unwrap();
+        let decoded = SecretKey::from_cose_key(&key).unwrap();
+        assert_eq!(decoded.sign(msg), sk.sign(msg));
+    }
+}
+
+#[test]
+fn rejects_other_curves() {
+    let mut rng = StdRng::seed_from_u64(0xc05e);
+    let pk = PublicKey::from(&SecretKey::random(&mut rng));
+
+    // Ed25519
+    let mut key = pk.to_cose_key();
+    key.params[0].1 = 6.into();
+    assert_eq!(PublicKey::from_cose_key(&key), Err(Error::UnsupportedKey));
+
+    let mut key = pk.to_cose_key();
+    key.params.retain(|(label, _)| *label != Label::Int(-2));
+    assert_eq!(PublicKey::from_cose_key(&key), Err(Error::InvalidEncoding));
+}
+
+#[test]
+fn sign1_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0xc05e);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let aad = b"external data";
+
+    let builder = CoseSign1Builder::new()
+        .protected(HeaderBuilder::new().key_id(b"key-1".to_vec()).build())
+        .payload(b"a payload".to_vec());
+    let sign1 = sk.sign_cose(builder, aad);
+
+    let sign1 = CoseSign1::from_slice(&sign1.to_vec().unwrap()).unwrap();
+    assert!(pk.verify_cose(&sign1, aad).is_ok());
+    assert_eq!(
+        pk.verify_cose(&sign1, b"other data"),
+        Err(Error::InvalidSignature)
+    );
+
+    let other = PublicKey::from(&SecretKey::random(&mut rng));
+    assert_eq!(other.verify_cose(&sign1, aad), Err(Error::InvalidSignature));
+
+    let mut tampered = sign1.clone();
+    tampered.payload = Some(b"another payload".to_vec());
+    assert_eq!(pk.verify_cose(&tampered, aad), Err(Error::InvalidSignature));
+
+    let mut truncated = sign1.clone();
+    truncated.signature.pop();
+    assert_eq!(
+        pk.verify_cose(&truncated, aad),
+        Err(Error::bad_length(47, 48))
+    );
+
+    // The algorithm is required, and must be the one of this crate.
+    assert_eq!(
+        sign1.protected.header.alg,
+        Some(Algorithm::PrivateUse(COSE_ALGORITHM))
+    );
+    let mut missing = sign1.clone();
+    missing.protected.header.alg = None;
+    assert_eq!(
+        pk.verify_cose(&missing, aad),
+        Err(Error::UnsupportedAlgorithm)
+    );
+    let mut other_alg = sign1;
+    other_alg.protected.header.alg =
+        Some(Algorithm::Assigned(iana::Algorithm::EdDSA));
+    assert_eq!(
+        pk.verify_cose(&other_alg, aad),
+        Err(Error::UnsupportedAlgorithm)
+    );
+}
+
+#[test]
+fn sign1_replaces_algorithm() {
+    let mut rng = StdRng::seed_from_u64(0xc05e);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+
+    let builder = CoseSign1Builder::new()
+        .protected(
+            HeaderBuilder::new()
+                .algorithm(iana::Algorithm::EdDSA)
+                .build(),
+        )
+        .payload(b"a payload".to_vec());
+    let sign1 = sk.sign_cose(builder, &[]);
+
+    let sign1 = CoseSign1::from_slice(&sign1.to_vec().unwrap()).unwrap();
+    assert_eq!(
+        sign1.protected.header.alg,
+        Some(Algorithm::PrivateUse(COSE_ALGORITHM))
+    );
+    assert!(pk.verify_cose(&sign1, &[]).is_ok());
+}
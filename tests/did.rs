@@ -0,0 +1,105 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "did-key")]
+
+use bls12_381_bls::{
+    Error, PublicKey, SecretKey, VerificationMethod, resolve_did_key,
+};
+use dusk_bytes::{BadLength, Serializable};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn multicodec_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0xd1d);
+    let pk = PublicKey::from(&SecretKey::random(&mut rng));
+
+    let bytes = pk.to_multicodec();
+    assert_eq!(bytes[..2], [0xeb, 0x01]);
+    assert_eq!(bytes[2..], pk.to_bytes());
+    assert_eq!(PublicKey::from_multicodec(&bytes), Ok(pk));
+
+    let multibase = pk.to_multibase();
+    // Every BLS12-381 G2 key starts with the same prefix.
+    assert!(multibase.starts_with("zUC7"));
+    assert_eq!(PublicKey::from_multibase(&multibase), Ok(pk));
+
+    let did = pk.to_did_key();
+    assert_eq!(did, format!("did:key:{multibase}"));
+    assert_eq!(PublicKey::from_did_key(&did), Ok(pk));
+}
+
+#[test]
+fn rejects_other_names() {
+    let mut rng = StdRng::seed_from_u64(0xd1d);
+    let pk = PublicKey::from(&SecretKey::random(&mut rng));
+
+    // bls12_381-g1-pub
+    let mut bytes = pk.to_multicodec();
+    bytes[0] = 0xea;
+    assert_eq!(
+        PublicKey::from_multicodec(&bytes),
+        Err(Error::UnsupportedKey)
+    );
+    assert_eq!(
+        PublicKey::from_multicodec(&pk.to_multicodec()[..50]),
+        Err(Error::bad_length(48, 96))
+    );
+
+    let multibase = pk.to_multibase();
+    assert_eq!(
+        PublicKey::from_multibase(&multibase[1..]),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        PublicKey::from_did_key(&format!("did:web:{multibase}")),
+        Err(Error::WrongPrefix)
+    );
+}
+
+#[test]
+fn resolve_verification_method() {
+    let mut rng = StdRng::seed_from_u64(0xd1d);
+    let pk = PublicKey::from(&SecretKey::random(&mut rng));
+    let multibase = pk.to_multibase();
+    let did = pk.to_did_key();
+
+    let method = resolve_did_key(&did).unwrap();
+    assert_eq!(method, VerificationMethod::from(&pk));
+    assert_eq!(method.id, format!("{did}#{multibase}"));
+    assert_eq!(method.type_, "Multikey");
+    assert_eq!(method.controller, did);
+    assert_eq!(
+        PublicKey::from_multibase(&method.public_key_multibase),
+        Ok(pk)
+    );
+
+    assert_eq!(resolve_did_key(&method.id), Ok(method));
+    assert_eq!(
+        resolve_did_key(&format!("{did}#key-1")),
+        Err(Error::InvalidEncoding)
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn verification_method_json() {
+    let mut rng = StdRng::seed_from_u64(0xd1d);
+    let pk = PublicKey::from(&SecretKey::random(&mut rng));
+    let method = VerificationMethod::from(&pk);
+
+    let json = serde_json::to_value(&method).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "id": method.id,
+            "type": "Multikey",
+            "controller": method.controller,
+            "publicKeyMultibase": method.public_key_multibase,
+        })
+    );
+}
@@ -0,0 +1,125 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use bls12_381_bls::{
+    Error, MultisigPublicKey, MultisigSignature, PublicKey, SecretKey,
+    Signature,
+};
+use dusk_bls12_381::hash_to_curve::{ExpandMsgXmd, HashToField, MapToCurve};
+use dusk_bls12_381::{G1Affine, G1Projective, G2Affine};
+use dusk_bytes::{Error as DuskBytesError, Serializable};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use sha2::Sha256;
+
+#[test]
+fn uncompressed_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0xc0ffee);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
+
+    let bytes = pk.to_uncompressed();
+    let point = G2Affine::from_bytes(&pk.to_bytes()).unwrap();
+    assert_eq!(bytes, point.to_uncompressed());
+    assert_eq!(PublicKey::from_uncompressed(&bytes), Ok(pk));
+
+    let apk = MultisigPublicKey::aggregate(&[pk]).unwrap();
+    let bytes = apk.to_uncompressed();
+    assert_eq!(MultisigPublicKey::from_uncompressed(&bytes), Ok(apk));
+
+    let sig = sk.sign(msg);
+    let bytes = sig.to_uncompressed();
+    assert_eq!(Signature::from_uncompressed(&bytes), Ok(sig));
+
+    let ms_sig = sk.sign_multisig(&pk, msg);
+    let bytes = ms_sig.to_uncompressed();
+    assert_eq!(MultisigSignature::from_uncompressed(&bytes), Ok(ms_sig));
+}
+
+#[test]
+fn uncompressed_rejects_invalid_points() {
+    let mut rng = StdRng::seed_from_u64(0xc0ffee);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let sig = sk.sign(b"a message");
+
+    // The compression flag must not be set.
+    let mut bytes = pk.to_uncompressed();
+    bytes[0] |= 0x80;
+    assert_eq!(
+        PublicKey::from_uncompressed(&bytes),
+        Err(Error::BytesError(DuskBytesError::InvalidData))
+    );
+
+    // Flip a bit of the y coordinate to move the point off the curve.
+    let mut bytes = sig.to_uncompressed();
+    bytes[95] ^= 1;
+    assert_eq!(
+        Signature::from_uncompressed(&bytes),
+        Err(Error::InvalidPoint)
+    );
+
+    assert_eq!(
+        PublicKey::from_uncompressed(&G2Affine::identity().to_uncompressed()),
+        Err(Error::IdentityPoint)
+    );
+    assert_eq!(
+        Signature::from_uncompressed(&G1Affine::identity().to_uncompressed()),
+        Err(Error::IdentityPoint)
+    );
+
+    // Mapping to the curve without clearing the cofactor lands outside of
+    // the prime-order subgroup.
+    let mut u = [<G1Projective as MapToCurve>::Field::default()];
+    HashToField::hash_to_field::<ExpandMsgXmd<Sha256>>(b"msg", b"dst", &mut u);
+    let point = G1Affine::from(G1Projective::map_to_curve(&u[0]));
+    assert_eq!(
+        MultisigSignature::from_uncompressed(&point.to_uncompressed()),
+        Err(Error::NotInSubgroup)
+    );
+}
+
+#[test]
+fn batch_deserialization() {
+    let mut rng = StdRng::seed_from_u64(0xba7c4);
+    let msg = b"a message";
+
+    let sks: Vec<_> = (0..16).map(|_| SecretKey::random(&mut rng)).collect();
+    let pks: Vec<_> = sks.iter().map(PublicKey::from).collect();
+    let sigs: Vec<_> = sks.iter().map(|sk| sk.sign(msg)).collect();
+    let ms_sigs: Vec<_> = sks
+        .iter()
+        .zip(&pks)
+        .map(|(sk, pk)| sk.sign_multisig(pk, msg))
+        .collect();
+
+    let mut pk_bytes: Vec<_> = pks.iter().map(|pk| pk.to_bytes()).collect();
+    let mut sig_bytes: Vec<_> = sigs.iter().map(|sig| sig.to_bytes()).collect();
+    let ms_sig_bytes: Vec<_> =
+        ms_sigs.iter().map(|sig| sig.to_bytes()).collect();
+
+    assert_eq!(PublicKey::from_bytes_batch(&pk_bytes), Ok(pks));
+    assert_eq!(Signature::from_bytes_batch(&sig_bytes), Ok(sigs));
+    assert_eq!(
+        MultisigSignature::from_bytes_batch(&ms_sig_bytes),
+        Ok(ms_sigs)
+    );
+    assert_eq!(PublicKey::from_bytes_batch(&[]), Ok(vec![]));
+
+    // The first failing index is reported.
+    pk_bytes[11] = [0xff; 96];
+    pk_bytes[5] = [0xff; 96];
+    assert_eq!(
+        PublicKey::from_bytes_batch(&pk_bytes),
+        Err((5, Error::BytesError(DuskBytesError::InvalidData)))
+    );
+    sig_bytes[15][0] &= 0x7f;
+    assert_eq!(
+        Signature::from_bytes_batch(&sig_bytes),
+        Err((15, Error::BytesError(DuskBytesError::InvalidData)))
+    );
+}
@@ -0,0 +1,141 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use bls12_381_bls::{
+    BlindSignature, BlindedMessage, Error, IdentitySecretKey,
+    IdentitySignature, PublicKey, SecretKey, Signature, VrfProof,
+};
+use dusk_bls12_381::hash_to_curve::{ExpandMsgXmd, HashToField, MapToCurve};
+use dusk_bls12_381::{BlsScalar, G2Affine, G2Projective};
+use dusk_bytes::{DeserializableSlice, Error as DuskBytesError, Serializable};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use sha2::Sha256;
+
+#[test]
+fn verify_reports_point_errors() {
+    let mut rng = StdRng::seed_from_u64(0xe770);
+    let sk = SecretKey::random(&mut rng);
+    let msg = b"a message";
+    let sig = sk.sign(msg);
+
+    let identity = PublicKey::from(&SecretKey::from(BlsScalar::zero()));
+    assert_eq!(identity.verify(&sig, msg), Err(Error::IdentityPoint));
+
+    let mut off_curve_raw = [0xffu8; G2Affine::RAW_SIZE];
+    off_curve_raw[G2Affine::RAW_SIZE - 1] = 0;
+    let off_curve = unsafe { PublicKey::from_slice_unchecked(&off_curve_raw) };
+    assert_eq!(off_curve.verify(&sig, msg), Err(Error::InvalidPoint));
+
+    // Mapping to the curve without clearing the cofactor lands outside of
+    // the prime-order subgroup.
+    let mut u = [<G2Projective as MapToCurve>::Field::default()];
+    HashToField::hash_to_field::<ExpandMsgXmd<Sha256>>(b"msg", b"dst", &mut u);
+    let point = G2Affine::from(G2Projective::map_to_curve(&u[0]));
+    let outside =
+        unsafe { PublicKey::from_slice_unchecked(&point.to_raw_bytes()) };
+    assert_eq!(outside.verify(&sig, msg), Err(Error::NotInSubgroup));
+}
+
+/// A compressed encoding of the small x-coordinate `x`.
+fn compressed<const N: usize>(x: u8) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    bytes[0] = 0x80;
+    bytes[N - 1] = x;
+    bytes
+}
+
+#[test]
+fn from_bytes_reports_point_errors() {
+    // x = 1 is not the x-coordinate of a point of either curve.
+    assert_eq!(
+        Signature::from_bytes(&compressed(1)),
+        Err(Error::InvalidPoint)
+    );
+    assert_eq!(
+        PublicKey::from_bytes(&compressed(1)),
+        Err(Error::InvalidPoint)
+    );
+
+    // x = 4 is, but not of a point in the prime-order subgroup.
+    assert_eq!(
+        Signature::from_bytes(&compressed(4)),
+        Err(Error::NotInSubgroup)
+    );
+    assert_eq!(
+        PublicKey::from_bytes(&compressed(4)),
+        Err(Error::NotInSubgroup)
+    );
+
+    // Encodings without the compression flag are malformed.
+    assert_eq!(
+        Signature::from_bytes(&[0u8; 48]),
+        Err(Error::BytesError(DuskBytesError::InvalidData))
+    );
+    assert_eq!(
+        PublicKey::from_bytes(&[0u8; 96]),
+        Err(Error::BytesError(DuskBytesError::InvalidData))
+    );
+
+    // The identity decodes, and is refused on use.
+    let mut identity = [0u8; 96];
+    identity[0] = 0xc0;
+    let identity = PublicKey::from_bytes(&identity).unwrap();
+    assert!(!identity.is_valid());
+}
+
+#[test]
+fn from_bytes_errors_are_uniform() {
+    // Every point type decodes like `Signature` and `PublicKey`.
+    let invalid = Some(Error::InvalidPoint);
+    assert_eq!(BlindedMessage::from_bytes(&compressed(1)).err(), invalid);
+    assert_eq!(BlindSignature::from_bytes(&compressed(1)).err(), invalid);
+    assert_eq!(VrfProof::from_bytes(&compressed(1)).err(), invalid);
+    assert_eq!(IdentitySecretKey::from_bytes(&compressed(1)).err(), invalid);
+
+    let mut sig = [0u8; 96];
+    sig[..48].copy_from_slice(&Signature::default().to_bytes());
+    sig[48..].copy_from_slice(&compressed::<48>(4));
+    assert_eq!(
+        IdentitySignature::from_bytes(&sig),
+        Err(Error::NotInSubgroup)
+    );
+
+    assert_eq!(
+        SecretKey::from_bytes(&[0xff; 32]).err(),
+        Some(Error::BytesError(DuskBytesError::InvalidData))
+    );
+}
+
+#[test]
+fn error_display() {
+    let err = Error::from(DuskBytesError::BadLength {
+        found: 3,
+        expected: 96,
+    });
+    assert_eq!(format!("{err}"), "Bad length: expected 96 bytes, found 3");
+    assert_eq!(
+        format!("{}", Signature::from_slice(&[0u8; 3]).unwrap_err()),
+        "Bad length: expected 48 bytes, found 3"
+    );
+    assert_eq!(
+        format!("{}", Error::InvalidKeyAt { index: 4 }),
+        "Invalid public key at index 4"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn error_is_std_error() {
+    fn boxed(err: Error) -> Box<dyn std::error::Error> {
+        Box::new(err)
+    }
+
+    assert_eq!(
+        boxed(Error::DuplicateSigner { index: 1 }).to_string(),
+        "Duplicate public key at index 1"
+    );
+}
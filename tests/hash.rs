@@ -0,0 +1,140 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use bls12_381_bls::{encode_to_g1, hash_to_g1, hash_to_scalar};
+use dusk_bls12_381::{BlsScalar, G1Affine};
+use sha2::{Digest, Sha256};
+
+// RFC9380 appendix J.9.1 and J.9.2 test vectors.
+const RO_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+const NU_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_";
+// RFC9380 appendix K.1 test vectors.
+const XMD_DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+fn point(x: &str, y: &str) -> G1Affine {
+    let mut bytes = [0u8; 96];
+    bytes[..48].copy_from_slice(&hex::decode(x).unwrap());
+    bytes[48..].copy_from_slice(&hex::decode(y).unwrap());
+    G1Affine::from_uncompressed(&bytes).expect("vector point must be valid")
+}
+
+#[test]
+fn hash_to_g1_rfc9380_vectors() {
+    let vectors = [
+        (
+            &b""[..],
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        ),
+        (
+            &b"abc"[..],
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        ),
+    ];
+
+    for (msg, x, y) in vectors {
+        assert_eq!(hash_to_g1(msg, RO_DST), point(x, y));
+    }
+}
+
+#[test]
+fn encode_to_g1_rfc9380_vectors() {
+    let vectors = [
+        (
+            &b""[..],
+            "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba",
+            "04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+        ),
+        (
+            &b"abc"[..],
+            "009769f3ab59bfd551d53a5f846b9984c59b97d6842b20a2c565baa167945e3d026a3755b6345df8ec7e6acb6868ae6d",
+            "1532c00cf61aa3d0ce3e5aa20c3b531a2abd2c770a790a2613818303c6b830ffc0ecf6c357af3317b9575c567f11cd2c",
+        ),
+    ];
+
+    for (msg, x, y) in vectors {
+        assert_eq!(encode_to_g1(msg, NU_DST), point(x, y));
+    }
+}
+
+#[test]
+fn hash_to_scalar_matches_expand_message_xmd() {
+    // Check the reference expander against RFC9380 appendix K.1 first.
+    assert_eq!(
+        hex::encode(expand_message_xmd(b"", XMD_DST, 0x20)),
+        "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"
+    );
+    assert_eq!(
+        hex::encode(expand_message_xmd(b"abc", XMD_DST, 0x20)),
+        "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"
+    );
+
+    for msg in [&b""[..], b"abc", b"abcdef0123456789"] {
+        let scalars = hash_to_scalar(msg, XMD_DST, 3);
+        let uniform = expand_message_xmd(msg, XMD_DST, 3 * 48);
+
+        assert_eq!(scalars.len(), 3);
+        for (scalar, okm) in scalars.iter().zip(uniform.chunks(48)) {
+            // OS2IP(okm) mod r
+            let mut wide = [0u8; 64];
+            wide[..48].copy_from_slice(okm);
+            wide[..48].reverse();
+            assert_eq!(*scalar, BlsScalar::from_bytes_wide(&wide));
+        }
+    }
+
+    assert!(hash_to_scalar(b"abc", XMD_DST, 0).is_empty());
+}
+
+#[test]
+fn hash_to_g1_is_domain_separated() {
+    let msg = b"a message";
+
+    assert_ne!(hash_to_g1(msg, RO_DST), hash_to_g1(msg, NU_DST));
+    assert_ne!(hash_to_g1(msg, RO_DST), encode_to_g1(msg, RO_DST));
+    assert_ne!(
+        hash_to_scalar(msg, RO_DST, 1),
+        hash_to_scalar(msg, NU_DST, 1)
+    );
+}
+
+// Straightforward implementation of RFC9380 section 5.3.1.
+fn expand_message_xmd(msg: &[u8], dst: &[u8], len: usize) -> Vec<u8> {
+    let ell = len.div_ceil(32);
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+
+    let b_0 = Sha256::new()
+        .chain([0u8; 64])
+        .chain(msg)
+        .chain((len as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(&dst_prime)
+        .finalize();
+
+    let mut b_i = Sha256::new()
+        .chain(b_0)
+        .chain([1u8])
+        .chain(&dst_prime)
+        .finalize();
+    let mut uniform = b_i.to_vec();
+
+    for i in 2..=ell {
+        let mut xored = [0u8; 32];
+        for (x, (a, b)) in xored.iter_mut().zip(b_0.iter().zip(b_i.iter())) {
+            *x = a ^ b;
+        }
+        b_i = Sha256::new()
+            .chain(xored)
+            .chain([i as u8])
+            .chain(&dst_prime)
+            .finalize();
+        uniform.extend_from_slice(&b_i);
+    }
+
+    uniform.truncate(len);
+    uniform
+}
@@ -0,0 +1,131 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use bls12_381_bls::beacon::timelock_encrypt;
+use bls12_381_bls::ibe::{Ciphertext, decrypt, encrypt};
+use bls12_381_bls::{Error, PublicKey, SecretKey, Signature};
+use dusk_bytes::{BadLength, Serializable};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+// `b"bid: 42"` encrypted to `b"alice"` under the master key drawn first
+// from `StdRng::seed_from_u64(0x1be)`, by this crate. It pins the encoding
+// against regressions, and is not a tlock test vector: the ciphertexts of
+// this crate are not compatible with tlock.
+const CIPHERTEXT: &str = "b5f1ab23591706baedaa80bfbbf1b3492de4d35a91e7f51f7c24cdfed91eda08435337672a655446bb4979684b56f1c809d69ebd54182f528d72d3a41fea9d4eed2e8634ffc0200ef02b1e51577747ed790bbede7aa1b38cf9f612258319d5a18f122f3a8746ac33c61c7fce2b5beba29d3a4639195207034f07b1689a880a6a43bc5c291de265";
+
+// drand quicknet and the signature of its round 1000.
+const QUICKNET_PK: &str = "83cf0f2896adee7eb8b5f01fcad3912212c437e0073e911fb90022d3e760183c8c4b450b6a0a6c3ac6a5776a2d1064510d1fec758c921cc22b0e17e63aaf4bcb5ed66304de9cf809bd274ca73bab4af5a6e9c76a4bc09e76eae8991ef5ece45a";
+const ROUND: u64 = 1000;
+const SIGNATURE: &str = "b44679b9a59af2ec876b1a6b1ad52ea9b1615fc3982b19576350f93447cb1125e342b73a8dd2bacbe47e4b6b63ed5e39";
+
+#[test]
+fn known_ciphertext() {
+    let mut rng = StdRng::seed_from_u64(0x1be);
+    let master = SecretKey::random(&mut rng);
+
+    let ct = Ciphertext::from_bytes(&hex::decode(CIPHERTEXT).unwrap())
+        .expect("valid ciphertext");
+    assert_eq!(decrypt(&master.sign(b"alice"), &ct).unwrap(), b"bid: 42");
+
+    // Encryption reproduces the vector byte for byte, which pins the hashes,
+    // including the encoding of the pairing result hashed by `H2`.
+    let encrypted =
+        encrypt(&mut rng, &PublicKey::from(&master), b"alice", b"bid: 42");
+    assert_eq!(hex::encode(encrypted.to_bytes()), CIPHERTEXT);
+}
+
+#[test]
+fn encrypt_decrypt() {
+    let mut rng = StdRng::seed_from_u64(0x1be);
+    let master = SecretKey::random(&mut rng);
+    let master_pk = PublicKey::from(&master);
+    let key = master.sign(b"alice");
+
+    for len in [0, 1, 31, 32, 33, 100] {
+        let msg: Vec<u8> = (0..len as u8).collect();
+        let ct = encrypt(&mut rng, &master_pk, b"alice", &msg);
+        assert_eq!(ct.to_bytes().len(), Ciphertext::OVERHEAD + len);
+        assert_eq!(decrypt(&key, &ct).unwrap(), msg);
+
+        let decoded = Ciphertext::from_bytes(&ct.to_bytes()).unwrap();
+        assert_eq!(decoded, ct);
+    }
+
+    // Encryption is randomized.
+    assert_ne!(
+        encrypt(&mut rng, &master_pk, b"alice", b"bid"),
+        encrypt(&mut rng, &master_pk, b"alice", b"bid")
+    );
+}
+
+#[test]
+fn wrong_key() {
+    let mut rng = StdRng::seed_from_u64(0x1be);
+    let master = SecretKey::random(&mut rng);
+    let other = SecretKey::random(&mut rng);
+    let ct = encrypt(&mut rng, &PublicKey::from(&master), b"alice", b"bid");
+
+    assert_eq!(
+        decrypt(&master.sign(b"bob"), &ct),
+        Err(Error::DecryptionFailed)
+    );
+    assert_eq!(
+        decrypt(&other.sign(b"alice"), &ct),
+        Err(Error::DecryptionFailed)
+    );
+}
+
+#[test]
+fn tampered_ciphertext() {
+    let mut rng = StdRng::seed_from_u64(0x1be);
+    let master = SecretKey::random(&mut rng);
+    let key = master.sign(b"alice");
+    let bytes =
+        encrypt(&mut rng, &PublicKey::from(&master), b"alice", b"bid: 42")
+            .to_bytes();
+
+    // Flipping a bit of V or W is detected.
+    for i in [Ciphertext::OVERHEAD - 1, Ciphertext::OVERHEAD] {
+        let mut tampered = bytes.clone();
+        tampered[i] ^= 1;
+        let ct = Ciphertext::from_bytes(&tampered).unwrap();
+        assert_eq!(decrypt(&key, &ct), Err(Error::DecryptionFailed));
+    }
+
+    // Truncating the message too.
+    let ct = Ciphertext::from_bytes(&bytes[..bytes.len() - 1]).unwrap();
+    assert_eq!(decrypt(&key, &ct), Err(Error::DecryptionFailed));
+}
+
+#[test]
+fn malformed_ciphertext() {
+    let bytes = [0u8; Ciphertext::OVERHEAD];
+    assert_eq!(
+        Ciphertext::from_bytes(&bytes[1..]),
+        Err(Error::bad_length(
+            Ciphertext::OVERHEAD - 1,
+            Ciphertext::OVERHEAD
+        ))
+    );
+    assert!(Ciphertext::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn timelock() {
+    let mut rng = StdRng::seed_from_u64(0x7106);
+    let pk = hex::decode(QUICKNET_PK).unwrap();
+    let pk = PublicKey::from_bytes(&pk.try_into().unwrap()).unwrap();
+    let sig = hex::decode(SIGNATURE).unwrap();
+    let sig = Signature::from_bytes(&sig.try_into().unwrap()).unwrap();
+
+    let ct = timelock_encrypt(&mut rng, &pk, ROUND, b"sealed bid");
+    assert_eq!(decrypt(&sig, &ct).unwrap(), b"sealed bid");
+
+    // The signature of another round doesn't decrypt.
+    let ct = timelock_encrypt(&mut rng, &pk, ROUND + 1, b"sealed bid");
+    assert_eq!(decrypt(&sig, &ct), Err(Error::DecryptionFailed));
+}
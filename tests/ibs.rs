@@ -0,0 +1,109 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use bls12_381_bls::{
+    Error, IdentitySecretKey, IdentitySignature, PublicKey, SecretKey,
+    Signature,
+};
+use dusk_bytes::Serializable;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+const NODE: &[u8] = b"node-7.subnet-3";
+
+#[test]
+fn sign_verify() {
+    let mut rng = StdRng::seed_from_u64(0x1b5);
+    let master = SecretKey::random(&mut rng);
+    let master_pk = PublicKey::from(&master);
+
+    let key = master.extract_identity_key(NODE);
+    assert!(master_pk.verify_identity_key(NODE, &key).is_ok());
+
+    let sig = key.sign(&mut rng, NODE, b"block 42");
+    assert!(sig.is_valid());
+    assert!(master_pk.verify_identity(NODE, b"block 42", &sig).is_ok());
+
+    // Signing is randomized.
+    assert_ne!(sig, key.sign(&mut rng, NODE, b"block 42"));
+}
+
+#[test]
+fn wrong_inputs() {
+    let mut rng = StdRng::seed_from_u64(0x1b5);
+    let master = SecretKey::random(&mut rng);
+    let master_pk = PublicKey::from(&master);
+    let other_pk = PublicKey::from(&SecretKey::random(&mut rng));
+
+    let key = master.extract_identity_key(NODE);
+    let sig = key.sign(&mut rng, NODE, b"block 42");
+
+    assert_eq!(
+        master_pk.verify_identity(NODE, b"block 43", &sig),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        master_pk.verify_identity(b"node-8.subnet-3", b"block 42", &sig),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        other_pk.verify_identity(NODE, b"block 42", &sig),
+        Err(Error::InvalidSignature)
+    );
+
+    // A key can't sign for another identity.
+    let sig = key.sign(&mut rng, b"node-8.subnet-3", b"block 42");
+    assert_eq!(
+        master_pk.verify_identity(b"node-8.subnet-3", b"block 42", &sig),
+        Err(Error::InvalidSignature)
+    );
+
+    assert_eq!(
+        other_pk.verify_identity_key(NODE, &key),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        master_pk.verify_identity_key(b"node-8.subnet-3", &key),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn identity_keys_are_not_signatures() {
+    let mut rng = StdRng::seed_from_u64(0x1b5);
+    let master = SecretKey::random(&mut rng);
+    let master_pk = PublicKey::from(&master);
+
+    let key = master.extract_identity_key(NODE);
+    let bytes = key.expose_secret().to_bytes();
+    let sig = Signature::from_bytes(&bytes).unwrap();
+    assert_eq!(master_pk.verify(&sig, NODE), Err(Error::InvalidSignature));
+    assert_ne!(master.sign(NODE).to_bytes(), bytes);
+}
+
+#[test]
+fn serialization() {
+    let mut rng = StdRng::seed_from_u64(0x1b5);
+    let master = SecretKey::random(&mut rng);
+
+    let key = master.extract_identity_key(NODE);
+    let bytes = key.expose_secret().to_bytes();
+    assert_eq!(IdentitySecretKey::from_bytes(&bytes).unwrap(), key);
+    assert_eq!(format!("{key:?}"), "IdentitySecretKey(<redacted>)");
+
+    let sig = key.sign(&mut rng, NODE, b"block 42");
+    assert_eq!(IdentitySignature::from_bytes(&sig.to_bytes()), Ok(sig));
+
+    assert!(!IdentitySignature::default().is_valid());
+    assert_eq!(
+        PublicKey::from(&master).verify_identity(
+            NODE,
+            b"block 42",
+            &IdentitySignature::default()
+        ),
+        Err(Error::IdentityPoint)
+    );
+}
@@ -0,0 +1,88 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "jwk")]
+
+use bls12_381_bls::{Error, Jwk, PublicKey, SecretKey};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn public_key_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0x15e);
+    let pk = PublicKey::from(&SecretKey::random(&mut rng));
+
+    let jwk = pk.to_jwk();
+    assert_eq!((jwk.kty.as_str(), jwk.crv.as_str()), ("OKP", "BLS12381G2"));
+    assert_eq!(jwk.y, None);
+    let json = serde_json::to_string(&jwk).unwrap();
+    assert!(!json.contains("\"d\""));
+    let jwk: Jwk = serde_json::from_str(&json).unwrap();
+    assert_eq!(PublicKey::from_jwk(&jwk), Ok(pk));
+
+    let jwk = pk.to_jwk_ec2();
+    assert_eq!(jwk.kty, "EC2");
+    let json = serde_json::to_string(&jwk).unwrap();
+    let jwk: Jwk = serde_json::from_str(&json).unwrap();
+    assert_eq!(PublicKey::from_jwk(&jwk), Ok(pk));
+}
+
+#[test]
+fn secret_key_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0x15e);
+    let sk = SecretKey::random(&mut rng);
+    let msg = b"a message";
+
+    for jwk in [sk.expose_secret().to_jwk(), sk.expose_secret().to_jwk_ec2()] {
+        assert!(!format!("{jwk:?}").contains(jwk.d.as_deref().unwrap()));
+        let json = serde_json::to_string(&jwk).unwrap();
+        let jwk: Jwk = serde_json::from_str(&json).unwrap();
+        assert_eq!(PublicKey::from_jwk(&jwk), Ok(PublicKey::from(&sk)));
+        let decoded = SecretKey::from_jwk(&jwk).unwrap();
+        assert_eq!(decoded.sign(msg), sk.sign(msg));
+    }
+}
+
+#[test]
+fn secret_key_is_big_endian() {
+    let sk = SecretKey::from(dusk_bls12_381::BlsScalar::from(1));
+    let d = sk.expose_secret().to_jwk().d.clone().unwrap();
+    // 31 zero bytes followed by 0x01.
+    assert_eq!(d, "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAE");
+}
+
+#[test]
+fn rejects_invalid_keys() {
+    let mut rng = StdRng::seed_from_u64(0x15e);
+    let sk = SecretKey::random(&mut rng);
+    let other = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+
+    let mut jwk = pk.to_jwk();
+    jwk.crv = "P-256".into();
+    assert_eq!(PublicKey::from_jwk(&jwk), Err(Error::UnsupportedKey));
+
+    let mut jwk = pk.to_jwk();
+    jwk.kty = "RSA".into();
+    assert_eq!(PublicKey::from_jwk(&jwk), Err(Error::UnsupportedKey));
+
+    let mut jwk = pk.to_jwk_ec2();
+    jwk.y = None;
+    assert_eq!(PublicKey::from_jwk(&jwk), Err(Error::InvalidEncoding));
+
+    let mut jwk = pk.to_jwk();
+    jwk.x.push('=');
+    assert_eq!(PublicKey::from_jwk(&jwk), Err(Error::InvalidEncoding));
+
+    assert_eq!(
+        SecretKey::from_jwk(&pk.to_jwk()),
+        Err(Error::InvalidEncoding)
+    );
+
+    let mut jwk = sk.expose_secret().to_jwk();
+    jwk.d = other.expose_secret().to_jwk().d.clone();
+    assert_eq!(SecretKey::from_jwk(&jwk), Err(Error::KeyMismatch));
+}
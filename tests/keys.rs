@@ -4,11 +4,12 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use bls12_381_bls::{Error, MultisigPublicKey, PublicKey, SecretKey};
+use bls12_381_bls::{Error, Keypair, MultisigPublicKey, PublicKey, SecretKey};
 use dusk_bls12_381::BlsScalar;
 use dusk_bytes::Serializable;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
+use subtle::ConstantTimeEq;
 use zeroize::Zeroize;
 
 #[test]
@@ -20,6 +21,26 @@ fn sk_zeroize() {
     assert_eq!(sk, SecretKey::default());
 }
 
+#[test]
+fn sk_debug_is_redacted() {
+    let sk = SecretKey::from(BlsScalar::from(42));
+    let debug = format!("{sk:?} {:?}", sk.expose_secret());
+
+    assert!(!debug.contains(&format!("{:?}", BlsScalar::from(42))));
+    assert!(debug.contains("redacted"));
+}
+
+#[test]
+fn sk_constant_time_eq() {
+    let mut rng = StdRng::seed_from_u64(0xc0ffee);
+    let sk = SecretKey::random(&mut rng);
+    let other = SecretKey::random(&mut rng);
+
+    assert!(bool::from(sk.ct_eq(&sk.clone())));
+    assert!(!bool::from(sk.ct_eq(&other)));
+    assert_ne!(sk, other);
+}
+
 #[test]
 fn keys_encoding() {
     let mut rng = StdRng::seed_from_u64(0xbeef);
@@ -28,7 +49,10 @@ fn keys_encoding() {
     let mspk = MultisigPublicKey::aggregate(&[pk])
         .expect("Aggregating should succeed");
 
-    assert_eq!(sk, SecretKey::from_bytes(&sk.to_bytes()).unwrap());
+    assert_eq!(
+        sk,
+        SecretKey::from_bytes(&sk.expose_secret().to_bytes()).unwrap()
+    );
     assert_eq!(pk, PublicKey::from_bytes(&pk.to_bytes()).unwrap());
     assert_eq!(
         mspk,
@@ -36,6 +60,66 @@ fn keys_encoding() {
     );
 }
 
+#[test]
+fn keypair_signs_like_secret_key() {
+    let mut rng = StdRng::seed_from_u64(0x6b70);
+    let keypair = Keypair::random(&mut rng);
+    let sk = keypair.secret_key();
+    let pk = keypair.public_key();
+    let msg = b"a message";
+
+    assert_eq!(*pk, PublicKey::from(sk));
+    assert_eq!(keypair.sign(msg), sk.sign(msg));
+    assert_eq!(keypair.sign_multisig(msg), sk.sign_multisig(pk, msg));
+
+    let apk = MultisigPublicKey::aggregate(&[*pk]).unwrap();
+    assert!(apk.verify(&keypair.sign_multisig(msg), msg).is_ok());
+}
+
+#[test]
+fn keypair_encoding() {
+    let mut rng = StdRng::seed_from_u64(0x6b70);
+    let keypair = Keypair::random(&mut rng);
+    let bytes = keypair.expose_secret().to_bytes();
+
+    assert_eq!(keypair, Keypair::from_bytes(&bytes).unwrap());
+
+    let other = Keypair::random(&mut rng);
+    let mut mismatched = bytes;
+    mismatched[SecretKey::SIZE..]
+        .copy_from_slice(&other.public_key().to_bytes());
+    assert_eq!(
+        Keypair::from_bytes(&mismatched).unwrap_err(),
+        Error::KeyMismatch
+    );
+}
+
+#[test]
+fn keypair_zeroize() {
+    let mut keypair = Keypair::from(SecretKey::from(BlsScalar::from(42)));
+
+    keypair.zeroize();
+    assert_eq!(*keypair.secret_key(), SecretKey::default());
+}
+
+#[test]
+fn sign_multisig_checked_rejects_mismatched_key() {
+    let mut rng = StdRng::seed_from_u64(0x6b70);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let other_pk = PublicKey::from(&SecretKey::random(&mut rng));
+    let msg = b"a message";
+
+    assert_eq!(
+        sk.sign_multisig_checked(&pk, msg).unwrap(),
+        sk.sign_multisig(&pk, msg)
+    );
+    assert_eq!(
+        sk.sign_multisig_checked(&other_pk, msg).unwrap_err(),
+        Error::KeyMismatch
+    );
+}
+
 #[test]
 fn apk_identity_fails() {
     let mut rng = StdRng::seed_from_u64(0xba0bab);
@@ -48,65 +132,52 @@ fn apk_identity_fails() {
 
     assert_eq!(
         MultisigPublicKey::aggregate(&[identity, pk1, pk2]).unwrap_err(),
-        Error::InvalidPoint
+        Error::InvalidKeyAt { index: 0 }
     );
     assert_eq!(
         MultisigPublicKey::aggregate(&[pk1, identity, pk2]).unwrap_err(),
-        Error::InvalidPoint
+        Error::InvalidKeyAt { index: 1 }
     );
     assert_eq!(
         MultisigPublicKey::aggregate(&[pk1, pk2, identity]).unwrap_err(),
-        Error::InvalidPoint
+        Error::InvalidKeyAt { index: 2 }
     );
 }
 
 #[test]
-fn apk_insecure_empty_input_fails() {
-    assert_eq!(
-        MultisigPublicKey::aggregate_insecure(&[]).unwrap_err(),
-        Error::NoKeysProvided
-    );
-}
+fn apk_duplicate_signer_fails() {
+    let mut rng = StdRng::seed_from_u64(0xd0b1e);
 
-#[test]
-fn apk_insecure_identity_fails() {
-    let mut rng = StdRng::seed_from_u64(0xba0bab);
-
-    let sk1 = SecretKey::random(&mut rng);
-    let pk1 = PublicKey::from(&sk1);
-    let sk2 = SecretKey::random(&mut rng);
-    let pk2 = PublicKey::from(&sk2);
-    let identity = PublicKey::from(&SecretKey::from(BlsScalar::zero()));
+    let pk1 = PublicKey::from(&SecretKey::random(&mut rng));
+    let pk2 = PublicKey::from(&SecretKey::random(&mut rng));
+    let pk3 = PublicKey::from(&SecretKey::random(&mut rng));
 
     assert_eq!(
-        MultisigPublicKey::aggregate_insecure(&[identity, pk1, pk2])
+        MultisigPublicKey::aggregate_distinct(&[pk1, pk2, pk3, pk2, pk1])
             .unwrap_err(),
-        Error::InvalidPoint
+        Error::DuplicateSigner { index: 3 }
     );
     assert_eq!(
-        MultisigPublicKey::aggregate_insecure(&[pk1, identity, pk2])
-            .unwrap_err(),
-        Error::InvalidPoint
+        MultisigPublicKey::aggregate_distinct(&[pk1, pk1]).unwrap_err(),
+        Error::DuplicateSigner { index: 1 }
     );
     assert_eq!(
-        MultisigPublicKey::aggregate_insecure(&[pk1, pk2, identity])
-            .unwrap_err(),
-        Error::InvalidPoint
+        MultisigPublicKey::aggregate_distinct(&[pk1, pk2, pk3]),
+        MultisigPublicKey::aggregate(&[pk1, pk2, pk3])
     );
 }
 
 #[test]
-fn apk_insecure_invalid_point_fails() {
-    let mut rng = StdRng::seed_from_u64(0xdecafbad);
-    let valid = PublicKey::from(&SecretKey::random(&mut rng));
+fn apk_counts_repeated_keys() {
+    let mut rng = StdRng::seed_from_u64(0xd0b1e);
 
-    let mut invalid_raw = [0xffu8; dusk_bls12_381::G2Affine::RAW_SIZE];
-    invalid_raw[dusk_bls12_381::G2Affine::RAW_SIZE - 1] = 0;
-    let invalid = unsafe { PublicKey::from_slice_unchecked(&invalid_raw) };
-    assert!(!invalid.is_valid());
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
 
-    assert_eq!(
-        MultisigPublicKey::aggregate_insecure(&[valid, invalid]).unwrap_err(),
-        Error::InvalidPoint
-    );
+    // A key repeated in the set signs once per appearance.
+    let apk = MultisigPublicKey::aggregate(&[pk, pk]).unwrap();
+    let sig = sk.sign_multisig(&pk, msg);
+    assert!(apk.verify(&sig.aggregate(&[sig]), msg).is_ok());
+    assert!(apk.verify(&sig, msg).is_err());
 }
@@ -0,0 +1,295 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "insecure-v1-verify")]
+
+use bls12_381_bls::{Error, MultisigPublicKey, PublicKey, SecretKey, legacy};
+#[cfg(feature = "insecure-v1-signing")]
+use bls12_381_bls::{MultisigSignature, Signature};
+use dusk_bls12_381::BlsScalar;
+#[cfg(feature = "insecure-v1-signing")]
+use dusk_bls12_381::{G1Affine, G1Projective};
+use dusk_bytes::Serializable;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+#[test]
+fn secure_signatures_are_not_valid_under_insecure_rules() {
+    let rng = &mut StdRng::seed_from_u64(0xdead);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let msg = random_message(rng);
+
+    let sig = sk.sign(&msg);
+    assert!(pk.verify(&sig, &msg).is_ok());
+    let legacy_pk = legacy::PublicKey::from(&pk);
+    let legacy_sig = legacy::Signature::from_bytes(&sig.to_bytes()).unwrap();
+    assert!(legacy_pk.verify(&legacy_sig, &msg).is_err());
+
+    let ms_sig = sk.sign_multisig(&pk, &msg);
+    let ms_sig =
+        legacy::MultisigSignature::from_bytes(&ms_sig.to_bytes()).unwrap();
+    let ms_pk = legacy::MultisigPublicKey::aggregate(&[legacy_pk])
+        .expect("insecure aggregation should succeed");
+    assert!(ms_pk.verify(&ms_sig, &msg).is_err());
+}
+
+#[test]
+fn insecure_legacy_vectors_remain_valid() {
+    const SINGLE_MSG: [u8; 100] = [
+        0x73, 0xff, 0x3f, 0xfc, 0x67, 0xe3, 0xc0, 0x8a, 0xc1, 0x1d, 0xf0, 0x30,
+        0x00, 0x02, 0x8f, 0x12, 0x47, 0x49, 0x52, 0x79, 0xe4, 0xe4, 0xbc, 0x89,
+        0xff, 0x7a, 0xcc, 0xbb, 0xb9, 0x44, 0x4e, 0x5f, 0xf3, 0x24, 0xc8, 0xbd,
+        0xef, 0xff, 0x0c, 0xb1, 0x53, 0xda, 0xdc, 0xa0, 0x70, 0xd9, 0x19, 0x7a,
+        0xb4, 0x56, 0xc4, 0x17, 0xa5, 0x16, 0x83, 0x60, 0x7f, 0x85, 0x6e, 0xe9,
+        0xd6, 0xac, 0xaa, 0xcf, 0x30, 0x3e, 0x24, 0xe7, 0x10, 0x8b, 0x12, 0x5b,
+        0x1e, 0x16, 0xf7, 0xec, 0xc9, 0xe5, 0x77, 0x31, 0x0f, 0x99, 0x51, 0x50,
+        0xd7, 0xbc, 0x58, 0x76, 0x5e, 0xc7, 0x56, 0xd3, 0xd3, 0x66, 0xc4, 0xf2,
+        0x6a, 0x47, 0x67, 0xe8,
+    ];
+    const SINGLE_PK: [u8; 96] = [
+        0xb8, 0x34, 0x2a, 0xba, 0x42, 0x3a, 0x84, 0x54, 0x3f, 0x42, 0xaa, 0xbf,
+        0xac, 0x36, 0x89, 0x3c, 0x52, 0x35, 0xe7, 0x80, 0x8c, 0xa8, 0x8e, 0xb4,
+        0xb7, 0x68, 0xa5, 0x23, 0x66, 0x75, 0xf9, 0xdb, 0x90, 0x33, 0xe5, 0x9a,
+        0x28, 0x13, 0x2b, 0xa5, 0x9d, 0x25, 0x6e, 0x3e, 0xe8, 0x78, 0xb1, 0xaf,
+        0x11, 0x4a, 0x1c, 0x6d, 0x47, 0x9d, 0x3a, 0x19, 0x25, 0x54, 0x4c, 0xf0,
+        0x1b, 0xce, 0xc9, 0x2f, 0x5f, 0x32, 0xd7, 0x0b, 0x4a, 0x50, 0xd1, 0xd0,
+        0x0f, 0xcf, 0x4e, 0x2d, 0x03, 0x5f, 0x9f, 0xa0, 0xe7, 0x7a, 0xf1, 0x53,
+        0x03, 0xfe, 0x23, 0x24, 0x9e, 0x1f, 0xc8, 0x5e, 0x9c, 0x7e, 0x3c, 0x79,
+    ];
+    const SINGLE_SIG: [u8; 48] = [
+        0x8e, 0x0c, 0xd9, 0x81, 0x1d, 0xcc, 0x78, 0xfa, 0xf6, 0x05, 0x25, 0x42,
+        0xb2, 0x87, 0xa0, 0x03, 0x3a, 0x2c, 0x52, 0x48, 0xb0, 0xdf, 0x0b, 0x41,
+        0x44, 0xb1, 0xb5, 0x56, 0xd6, 0x55, 0x23, 0x59, 0x04, 0x03, 0x68, 0xe7,
+        0xd4, 0xf1, 0x85, 0x1b, 0x81, 0x91, 0x04, 0xc0, 0xe0, 0x8d, 0x66, 0xcf,
+    ];
+    const MULTI_PK1: [u8; 96] = [
+        0x90, 0x1a, 0x8c, 0x70, 0xcb, 0x24, 0x3d, 0xbc, 0x9b, 0xc7, 0x01, 0x16,
+        0xa6, 0x3e, 0x6d, 0x43, 0x4b, 0xc9, 0x1e, 0xd3, 0x01, 0xee, 0x9e, 0xb9,
+        0xdf, 0x38, 0xaf, 0x87, 0x36, 0xec, 0xeb, 0xdd, 0x8c, 0x0b, 0xaa, 0x72,
+        0xf2, 0x96, 0xa5, 0xa5, 0xf5, 0x20, 0x14, 0xb0, 0xc7, 0xa1, 0xe3, 0x05,
+        0x01, 0xb9, 0x1c, 0x62, 0x9b, 0x21, 0xeb, 0x01, 0x20, 0xeb, 0xfc, 0x16,
+        0x9c, 0xbf, 0x8a, 0x47, 0x15, 0x1b, 0x6c, 0x22, 0x01, 0xed, 0x51, 0xa7,
+        0xbd, 0x76, 0xb5, 0x60, 0x07, 0x7a, 0xdc, 0x36, 0x37, 0xb1, 0xd2, 0xd1,
+        0x50, 0x4b, 0x2f, 0xea, 0xdd, 0x20, 0xae, 0x18, 0x70, 0x45, 0x34, 0x64,
+    ];
+    const MULTI_PK2: [u8; 96] = [
+        0x83, 0xaa, 0xbf, 0x4a, 0x7b, 0xb1, 0x9c, 0x3e, 0x94, 0xbd, 0x81, 0xc2,
+        0x4e, 0x17, 0xb8, 0x6d, 0xa5, 0x8a, 0xfc, 0x2c, 0x31, 0x2f, 0xef, 0x0f,
+        0x2f, 0x2e, 0x29, 0x98, 0x85, 0xbc, 0x94, 0xe1, 0x7d, 0xdf, 0x8f, 0x2c,
+        0x98, 0xb6, 0xf8, 0x36, 0x6a, 0xde, 0x21, 0xfb, 0x1a, 0x8b, 0x27, 0x93,
+        0x04, 0x1d, 0xcf, 0xa7, 0xe6, 0xeb, 0x63, 0x89, 0x81, 0x3a, 0x5f, 0x55,
+        0x84, 0xea, 0x0d, 0x9b, 0x95, 0xda, 0x53, 0xe5, 0xa0, 0x55, 0x1c, 0x6b,
+        0x86, 0x1e, 0x35, 0x49, 0xe5, 0xc4, 0x23, 0xaf, 0x90, 0x42, 0xd4, 0x4d,
+        0x7d, 0x62, 0x87, 0x8d, 0xfd, 0x71, 0xdd, 0x6b, 0xa1, 0x8e, 0xcd, 0xa1,
+    ];
+    const MULTI_AGG_PK: [u8; 96] = [
+        0x94, 0x2d, 0x00, 0xb9, 0xb7, 0xd7, 0x10, 0x36, 0x30, 0xf0, 0x29, 0xb9,
+        0x1a, 0xfa, 0x19, 0xca, 0x58, 0x62, 0x56, 0xb2, 0x1c, 0x32, 0xdd, 0xa0,
+        0x96, 0x87, 0xcf, 0x8d, 0x1e, 0x0d, 0x32, 0x29, 0x3f, 0xa9, 0x31, 0xab,
+        0x87, 0x34, 0x26, 0x1b, 0x4c, 0x0a, 0x86, 0x54, 0x64, 0x43, 0x67, 0xe1,
+        0x02, 0x4e, 0xf8, 0xd3, 0x5b, 0xab, 0x25, 0xde, 0x9e, 0x6a, 0xdb, 0xf8,
+        0x8f, 0xdd, 0xf4, 0x84, 0x03, 0x22, 0xd8, 0x0d, 0x6c, 0x9e, 0x2a, 0x10,
+        0x89, 0x59, 0xee, 0x43, 0x71, 0x75, 0x76, 0x5a, 0x8d, 0xf8, 0x60, 0x8b,
+        0x0f, 0x8f, 0x61, 0x7d, 0xfb, 0x38, 0xf9, 0x07, 0x44, 0xf4, 0x38, 0x28,
+    ];
+    const MULTI_SIG: [u8; 48] = [
+        0x81, 0x18, 0x79, 0xac, 0x91, 0xa2, 0x77, 0x82, 0xa8, 0x7f, 0xe1, 0x76,
+        0x8f, 0xf1, 0xc4, 0xb8, 0x6c, 0x00, 0x9d, 0x45, 0x74, 0x2f, 0x1a, 0xb2,
+        0xd9, 0xca, 0x4f, 0xc9, 0x52, 0x01, 0x1f, 0xf5, 0x8d, 0xeb, 0x97, 0xd6,
+        0x31, 0x0d, 0x9f, 0xdb, 0xcf, 0x29, 0x06, 0xd7, 0x57, 0xaf, 0xdf, 0x10,
+    ];
+
+    let msg = SINGLE_MSG;
+
+    let pk = legacy::PublicKey::from_bytes(&SINGLE_PK)
+        .expect("legacy vector public key should deserialize");
+    let sig = legacy::Signature::from_bytes(&SINGLE_SIG)
+        .expect("legacy vector signature should deserialize");
+    assert!(pk.verify(&sig, &msg).is_ok());
+
+    let secure_pk = PublicKey::from_bytes(&SINGLE_PK).unwrap();
+    let secure_sig = bls12_381_bls::Signature::from_bytes(&SINGLE_SIG).unwrap();
+    assert!(secure_pk.verify(&secure_sig, &msg).is_err());
+
+    let pk1 = legacy::PublicKey::from_bytes(&MULTI_PK1)
+        .expect("legacy vector multisig key 1 should deserialize");
+    let pk2 = legacy::PublicKey::from_bytes(&MULTI_PK2)
+        .expect("legacy vector multisig key 2 should deserialize");
+    let expected_agg = legacy::MultisigPublicKey::from_bytes(&MULTI_AGG_PK)
+        .expect("legacy vector aggregate key should deserialize");
+    let agg = legacy::MultisigPublicKey::aggregate(&[pk1, pk2])
+        .expect("legacy insecure aggregation should succeed");
+    assert_eq!(agg, expected_agg);
+
+    let ms_sig = legacy::MultisigSignature::from_bytes(&MULTI_SIG)
+        .expect("legacy vector multisig signature should deserialize");
+    assert!(agg.verify(&ms_sig, &msg).is_ok());
+
+    let secure_agg = MultisigPublicKey::from_bytes(&MULTI_AGG_PK).unwrap();
+    let secure_ms_sig =
+        bls12_381_bls::MultisigSignature::from_bytes(&MULTI_SIG).unwrap();
+    assert!(secure_agg.verify(&secure_ms_sig, &msg).is_err());
+}
+
+#[test]
+fn apk_insecure_empty_input_fails() {
+    assert_eq!(
+        legacy::MultisigPublicKey::aggregate(&[]).unwrap_err(),
+        Error::NoKeysProvided
+    );
+}
+
+#[test]
+fn apk_insecure_identity_fails() {
+    let mut rng = StdRng::seed_from_u64(0xba0bab);
+
+    let pk1 = legacy::PublicKey::from(&SecretKey::random(&mut rng));
+    let pk2 = legacy::PublicKey::from(&SecretKey::random(&mut rng));
+    let identity = legacy::PublicKey::from(&SecretKey::from(BlsScalar::zero()));
+
+    assert_eq!(
+        legacy::MultisigPublicKey::aggregate(&[identity, pk1, pk2])
+            .unwrap_err(),
+        Error::InvalidKeyAt { index: 0 }
+    );
+    assert_eq!(
+        legacy::MultisigPublicKey::aggregate(&[pk1, identity, pk2])
+            .unwrap_err(),
+        Error::InvalidKeyAt { index: 1 }
+    );
+    assert_eq!(
+        legacy::MultisigPublicKey::aggregate(&[pk1, pk2, identity])
+            .unwrap_err(),
+        Error::InvalidKeyAt { index: 2 }
+    );
+}
+
+#[test]
+fn apk_insecure_invalid_point_fails() {
+    let mut rng = StdRng::seed_from_u64(0xdecafbad);
+    let valid = legacy::PublicKey::from(&SecretKey::random(&mut rng));
+
+    let mut invalid_raw = [0xffu8; dusk_bls12_381::G2Affine::RAW_SIZE];
+    invalid_raw[dusk_bls12_381::G2Affine::RAW_SIZE - 1] = 0;
+    let invalid = unsafe { PublicKey::from_slice_unchecked(&invalid_raw) };
+    let invalid = legacy::PublicKey::from(&invalid);
+    assert!(!invalid.is_valid());
+
+    assert_eq!(
+        legacy::MultisigPublicKey::aggregate(&[valid, invalid]).unwrap_err(),
+        Error::InvalidKeyAt { index: 1 }
+    );
+}
+
+#[test]
+fn legacy_from_bytes_reports_point_errors() {
+    let mut bytes = [0u8; 96];
+    bytes[0] = 0x80;
+    bytes[95] = 1;
+    assert_eq!(
+        legacy::PublicKey::from_bytes(&bytes).unwrap_err(),
+        Error::InvalidPoint
+    );
+    assert_eq!(
+        legacy::MultisigPublicKey::from_bytes(&[0u8; 96]).unwrap_err(),
+        Error::BytesError(dusk_bytes::Error::InvalidData)
+    );
+}
+
+#[test]
+fn apk_insecure_accepts_duplicates() {
+    let mut rng = StdRng::seed_from_u64(0xd0b1e);
+    let pk = legacy::PublicKey::from(&SecretKey::random(&mut rng));
+
+    assert!(legacy::MultisigPublicKey::aggregate(&[pk, pk]).is_ok());
+}
+
+#[test]
+#[cfg(feature = "insecure-v1-signing")]
+fn insecure_signatures_are_not_valid_under_secure_rules() {
+    let rng = &mut StdRng::seed_from_u64(0xcafe);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let legacy_pk = legacy::PublicKey::from(&pk);
+    let msg = random_message(rng);
+
+    let sig = sk.sign_insecure(&msg);
+    assert!(legacy_pk.verify(&sig, &msg).is_ok());
+    let secure_sig = Signature::from_bytes(&sig.to_bytes()).unwrap();
+    assert!(pk.verify(&secure_sig, &msg).is_err());
+
+    let ms_sig = sk.sign_multisig_insecure(&legacy_pk, &msg);
+    let ms_pk_insecure = legacy::MultisigPublicKey::aggregate(&[legacy_pk])
+        .expect("insecure aggregation should succeed");
+    assert!(ms_pk_insecure.verify(&ms_sig, &msg).is_ok());
+
+    let ms_pk_secure = MultisigPublicKey::aggregate(&[pk])
+        .expect("aggregation should succeed");
+    let secure_ms_sig =
+        MultisigSignature::from_bytes(&ms_sig.to_bytes()).unwrap();
+    assert!(ms_pk_secure.verify(&secure_ms_sig, &msg).is_err());
+}
+
+#[test]
+#[cfg(feature = "insecure-v1-signing")]
+fn insecure_linear_forgery_is_rejected_by_secure_verifier() {
+    let rng = &mut StdRng::seed_from_u64(0x1337);
+
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let legacy_pk = legacy::PublicKey::from(&pk);
+
+    let msg1 = random_message(rng);
+    let msg2 = random_message(rng);
+    let msg3 = random_message(rng);
+
+    let sig1 = sk.sign_insecure(&msg1);
+    let sig2 = sk.sign_insecure(&msg2);
+
+    let h1 = BlsScalar::hash_to_scalar(&msg1);
+    let h2 = nonzero_hash(&msg2);
+    let h3 = BlsScalar::hash_to_scalar(&msg3);
+
+    // Choose a = 1 and solve b such that h1 + b*h2 = h3 (mod r).
+    let b = (h3 - h1) * h2.invert().expect("non-zero scalar must invert");
+
+    let s1 = sig_to_projective(&sig1);
+    let s2 = sig_to_projective(&sig2);
+    let forged: G1Affine = (s1 + s2 * b).into();
+
+    let legacy_forged = legacy::Signature::from_bytes(&forged.to_bytes())
+        .expect("constructed projective should serialize to signature");
+    assert!(legacy_pk.verify(&legacy_forged, &msg3).is_ok());
+
+    let forged = Signature::from_bytes(&forged.to_bytes())
+        .expect("constructed projective should serialize to signature");
+    assert!(pk.verify(&forged, &msg3).is_err());
+}
+
+fn random_message(rng: &mut StdRng) -> [u8; 100] {
+    let mut msg = [0u8; 100];
+    rng.fill_bytes(&mut msg);
+    msg
+}
+
+#[cfg(feature = "insecure-v1-signing")]
+fn sig_to_projective(sig: &legacy::Signature) -> G1Projective {
+    let bytes = sig.to_bytes();
+    let affine = G1Affine::from_bytes(&bytes).expect("signature bytes valid");
+    G1Projective::from(affine)
+}
+
+#[cfg(feature = "insecure-v1-signing")]
+fn nonzero_hash(msg: &[u8]) -> BlsScalar {
+    let mut h = BlsScalar::hash_to_scalar(msg);
+    if h.is_zero().into() {
+        h = BlsScalar::one();
+    }
+    h
+}
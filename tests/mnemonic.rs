@@ -0,0 +1,85 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "mnemonic")]
+
+use bls12_381_bls::{Error, Mnemonic, PublicKey, SecretKey};
+use dusk_bytes::BadLength;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon \
+                      abandon abandon abandon abandon about";
+
+// BIP-39 test vector for all-zero entropy with the "TREZOR" passphrase.
+const SEED: &str = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+
+// EIP-2333 master key of the seed above, in big-endian.
+const MASTER_SK: &str =
+    "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070";
+
+#[test]
+fn test_vectors() {
+    let mnemonic = Mnemonic::from_entropy(&[0u8; 16]).unwrap();
+    assert_eq!(mnemonic.to_string(), PHRASE);
+    assert_eq!(mnemonic.word_count(), 12);
+    assert_eq!(PHRASE.parse(), Ok(mnemonic.clone()));
+
+    let seed = mnemonic.to_seed("TREZOR");
+    assert_eq!(hex::encode(&seed[..]), SEED);
+
+    let sk = SecretKey::from_mnemonic(&mnemonic, "TREZOR");
+    let mut bytes = sk.expose_secret().to_bytes();
+    bytes.reverse();
+    assert_eq!(hex::encode(bytes), MASTER_SK);
+}
+
+#[test]
+fn backup_and_restore() {
+    let mut rng = StdRng::seed_from_u64(0xb1739);
+    let mnemonic = Mnemonic::generate(&mut rng);
+    assert_eq!(mnemonic.word_count(), 24);
+
+    let sk = SecretKey::from_mnemonic(&mnemonic, "");
+    let restored = Mnemonic::parse(&mnemonic.to_string()).unwrap();
+    let restored = SecretKey::from_mnemonic(&restored, "");
+    assert_eq!(PublicKey::from(&restored), PublicKey::from(&sk));
+
+    // The passphrase is part of the key.
+    let other = SecretKey::from_mnemonic(&mnemonic, "passphrase");
+    assert_ne!(PublicKey::from(&other), PublicKey::from(&sk));
+
+    assert_eq!(format!("{mnemonic:?}"), "Mnemonic(\"<redacted>\")");
+}
+
+#[test]
+fn rejects_typos() {
+    let typo = PHRASE.replacen("abandon", "abandn", 1);
+    assert_eq!(
+        typo.parse::<Mnemonic>(),
+        Err(Error::UnknownWord { index: 0 })
+    );
+
+    let swapped = PHRASE.replacen("about", "abandon", 1);
+    assert_eq!(swapped.parse::<Mnemonic>(), Err(Error::InvalidMnemonic));
+
+    let short = PHRASE.replacen("abandon ", "", 1);
+    assert_eq!(short.parse::<Mnemonic>(), Err(Error::InvalidMnemonic));
+
+    assert_eq!(
+        Mnemonic::from_entropy(&[0u8; 15]),
+        Err(Error::InvalidMnemonic)
+    );
+}
+
+#[test]
+fn seed_too_short() {
+    assert_eq!(
+        SecretKey::from_seed(&[0u8; 31]).map(|sk| PublicKey::from(&sk)),
+        Err(Error::bad_length(31, 32))
+    );
+    assert!(SecretKey::from_seed(&[0u8; 32]).is_ok());
+}
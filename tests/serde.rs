@@ -6,12 +6,15 @@
 
 #![cfg(feature = "serde")]
 
+use bls12_381_bls::{ExposedSecretKey, serde_encoding};
 use bls12_381_bls::{
-    MultisigPublicKey, MultisigSignature, PublicKey, SecretKey, Signature,
+    Keypair, MultisigPublicKey, MultisigSignature, PublicKey, SecretKey,
+    Signature,
 };
+use dusk_bytes::Serializable;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 fn assert_canonical_json<T>(
     input: &T,
@@ -90,7 +93,7 @@ fn serde_secret_key() -> Result<(), Box<dyn std::error::Error>> {
     let mut rng = StdRng::seed_from_u64(0xbeef);
     let sk = SecretKey::random(&mut rng);
     let ser = assert_canonical_json(
-        &sk,
+        &sk.expose_secret(),
         "\"J96A6LyxZL3JdymeEHL4bNhf5MmcmgSLkd6Umh5ELrPt\"",
     )?;
     let deser = serde_json::from_str(&ser)?;
@@ -98,24 +101,34 @@ fn serde_secret_key() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn serde_keypair() -> Result<(), Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(0xbeef);
+    let keypair = Keypair::random(&mut rng);
+    let ser = serde_json::to_string(&keypair.expose_secret())?;
+    let deser: Keypair = serde_json::from_str(&ser)?;
+    assert_eq!(keypair, deser);
+    Ok(())
+}
+
 #[test]
 fn serde_wrong_encoded() {
     let wrong_encoded = "\"wrong-encoded\"";
-    let public_key: Result<PublicKey, _> = serde_json::from_str(&wrong_encoded);
+    let public_key: Result<PublicKey, _> = serde_json::from_str(wrong_encoded);
     assert!(public_key.is_err());
 
-    let secret_key: Result<SecretKey, _> = serde_json::from_str(&wrong_encoded);
+    let secret_key: Result<SecretKey, _> = serde_json::from_str(wrong_encoded);
     assert!(secret_key.is_err());
 
-    let signature: Result<Signature, _> = serde_json::from_str(&wrong_encoded);
+    let signature: Result<Signature, _> = serde_json::from_str(wrong_encoded);
     assert!(signature.is_err());
 
     let public_key_double: Result<MultisigPublicKey, _> =
-        serde_json::from_str(&wrong_encoded);
+        serde_json::from_str(wrong_encoded);
     assert!(public_key_double.is_err());
 
     let signature_double: Result<MultisigSignature, _> =
-        serde_json::from_str(&wrong_encoded);
+        serde_json::from_str(wrong_encoded);
     assert!(signature_double.is_err());
 }
 
@@ -125,21 +138,21 @@ fn serde_too_long_encoded() {
     let length_49_enc = "\"RCR6kPYZDuew8ovT9MoxVv7mKRsbygumf2UTjvzs6AJhnukLj3BiFvjaE45Q41tKqdA\"";
     let length_97_enc = "\"7a5RpCdtr1aaXvaR3AofnEnVRh7kpzyqE8eYJpCBVLKLLpXVeN9UrXGRTZyq2upTVaJT5QnPQwZCGXW1oxrEAzrPvQ4vbWFwiHMJijZMzrPsTjQJFju1H4shrajuqUG4fYFpC\"";
 
-    let public_key: Result<PublicKey, _> = serde_json::from_str(&length_97_enc);
+    let public_key: Result<PublicKey, _> = serde_json::from_str(length_97_enc);
     assert!(public_key.is_err());
 
-    let secret_key: Result<SecretKey, _> = serde_json::from_str(&length_33_enc);
+    let secret_key: Result<SecretKey, _> = serde_json::from_str(length_33_enc);
     assert!(secret_key.is_err());
 
-    let signature: Result<Signature, _> = serde_json::from_str(&length_49_enc);
+    let signature: Result<Signature, _> = serde_json::from_str(length_49_enc);
     assert!(signature.is_err());
 
     let multisig_public_key: Result<MultisigPublicKey, _> =
-        serde_json::from_str(&length_97_enc);
+        serde_json::from_str(length_97_enc);
     assert!(multisig_public_key.is_err());
 
     let multisig_signature: Result<MultisigSignature, _> =
-        serde_json::from_str(&length_49_enc);
+        serde_json::from_str(length_49_enc);
     assert!(multisig_signature.is_err());
 }
 
@@ -150,20 +163,166 @@ fn serde_too_short_encoded() {
         "\"2F3DDEDEuxrszs3JfzFq51tnGNm3ZtrHwa7sAA4pkeo1JkqGTEYudnBZLNAkCohAd\"";
     let length_95_enc = "\"LZXkPWnz5xKxYnyDRZyJvL9vF44oQynzozqRBcpgWA3yZicbaxNeKKJrAMv3eXBbyEvk24mgz9Kg9tck5yEW6k16chN4hDWYUr5gDb9PJJ3YmUqcjG8yPaAuz3cNCE8dHv\"";
 
-    let public_key: Result<PublicKey, _> = serde_json::from_str(&length_95_enc);
+    let public_key: Result<PublicKey, _> = serde_json::from_str(length_95_enc);
     assert!(public_key.is_err());
 
-    let secret_key: Result<SecretKey, _> = serde_json::from_str(&length_31_enc);
+    let secret_key: Result<SecretKey, _> = serde_json::from_str(length_31_enc);
     assert!(secret_key.is_err());
 
-    let signature: Result<Signature, _> = serde_json::from_str(&length_47_enc);
+    let signature: Result<Signature, _> = serde_json::from_str(length_47_enc);
     assert!(signature.is_err());
 
     let multisig_public_key: Result<MultisigPublicKey, _> =
-        serde_json::from_str(&length_95_enc);
+        serde_json::from_str(length_95_enc);
     assert!(multisig_public_key.is_err());
 
     let multisig_signature: Result<MultisigSignature, _> =
-        serde_json::from_str(&length_47_enc);
+        serde_json::from_str(length_47_enc);
     assert!(multisig_signature.is_err());
 }
+
+#[test]
+fn serde_binary_formats_use_raw_bytes() -> Result<(), Box<dyn std::error::Error>>
+{
+    let mut rng = StdRng::seed_from_u64(0xbeef);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let apk = MultisigPublicKey::aggregate(&[pk]).unwrap();
+    let sig = sk.sign(b"a message");
+    let ms_sig = sk.sign_multisig(&pk, b"a message");
+    let keypair = Keypair::from(&sk);
+
+    let ser = bincode::serialize(&pk)?;
+    assert_eq!(ser, pk.to_bytes());
+    assert_eq!(bincode::deserialize::<PublicKey>(&ser)?, pk);
+
+    let ser = bincode::serialize(&apk)?;
+    assert_eq!(ser, apk.to_bytes());
+    assert_eq!(bincode::deserialize::<MultisigPublicKey>(&ser)?, apk);
+
+    let ser = bincode::serialize(&sig)?;
+    assert_eq!(ser, sig.to_bytes());
+    assert_eq!(bincode::deserialize::<Signature>(&ser)?, sig);
+
+    let ser = bincode::serialize(&ms_sig)?;
+    assert_eq!(ser, ms_sig.to_bytes());
+    assert_eq!(bincode::deserialize::<MultisigSignature>(&ser)?, ms_sig);
+
+    let ser = bincode::serialize(&sk.expose_secret())?;
+    assert_eq!(ser, sk.expose_secret().to_bytes());
+    assert_eq!(bincode::deserialize::<SecretKey>(&ser)?, sk);
+
+    let ser = bincode::serialize(&keypair.expose_secret())?;
+    assert_eq!(bincode::deserialize::<Keypair>(&ser)?, keypair);
+
+    assert!(bincode::deserialize::<PublicKey>(&ser[..95]).is_err());
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Encoded {
+    #[serde(with = "serde_encoding::hex")]
+    pk: PublicKey,
+    #[serde(with = "serde_encoding::base64")]
+    apk: MultisigPublicKey,
+    #[serde(with = "serde_encoding::bs58")]
+    sig: Signature,
+    #[serde(with = "serde_encoding::uncompressed")]
+    ms_sig: MultisigSignature,
+    #[serde(with = "serde_encoding::uncompressed")]
+    uncompressed_pk: PublicKey,
+}
+
+#[test]
+fn serde_encoding_adaptors() -> Result<(), Box<dyn std::error::Error>> {
+    let mut rng = StdRng::seed_from_u64(0xbeef);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let encoded = Encoded {
+        pk,
+        apk: MultisigPublicKey::aggregate(&[pk]).unwrap(),
+        sig: sk.sign(b"a message"),
+        ms_sig: sk.sign_multisig(&pk, b"a message"),
+        uncompressed_pk: pk,
+    };
+
+    let json = serde_json::to_value(&encoded)?;
+    assert_eq!(json["pk"], hex::encode(pk.to_bytes()));
+    assert_eq!(json["sig"], serde_json::to_value(encoded.sig)?);
+    assert_eq!(json["ms_sig"].as_array().unwrap().len(), 96);
+    assert_eq!(json["uncompressed_pk"].as_array().unwrap().len(), 192);
+    assert_eq!(serde_json::from_value::<Encoded>(json)?, encoded);
+
+    let ser = bincode::serialize(&encoded)?;
+    assert_eq!(bincode::deserialize::<Encoded>(&ser)?, encoded);
+
+    let mut json = serde_json::to_value(&encoded)?;
+    json["pk"] = hex::encode([0u8; 95]).into();
+    assert!(serde_json::from_value::<Encoded>(json).is_err());
+    Ok(())
+}
+
+#[test]
+fn serde_encoding_raw() -> Result<(), Box<dyn std::error::Error>> {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Cached {
+        #[serde(with = "serde_encoding::raw")]
+        pk: PublicKey,
+        #[serde(with = "serde_encoding::raw")]
+        sig: Signature,
+    }
+
+    let mut rng = StdRng::seed_from_u64(0xcac4e);
+    let sk = SecretKey::random(&mut rng);
+    let cached = Cached {
+        pk: PublicKey::from(&sk),
+        sig: sk.sign(b"a message"),
+    };
+
+    let json = serde_json::to_value(&cached)?;
+    assert_eq!(json["pk"].as_array().unwrap().len(), 193);
+    assert_eq!(json["sig"].as_array().unwrap().len(), 97);
+    assert_eq!(serde_json::from_value::<Cached>(json)?, cached);
+
+    let ser = bincode::serialize(&cached)?;
+    assert_eq!(bincode::deserialize::<Cached>(&ser)?, cached);
+    Ok(())
+}
+
+#[test]
+fn serde_encoding_rejects_long_sequences() {
+    #[derive(Debug, Deserialize)]
+    struct Encoded {
+        #[serde(with = "serde_encoding::uncompressed")]
+        _pk: PublicKey,
+    }
+
+    let json = serde_json::json!({ "_pk": vec![0u8; 1 << 16] });
+    assert!(serde_json::from_value::<Encoded>(json).is_err());
+}
+
+#[test]
+fn serde_encoding_secret_key() -> Result<(), Box<dyn std::error::Error>> {
+    #[derive(Serialize)]
+    struct Export<'a> {
+        #[serde(serialize_with = "serde_encoding::hex::serialize")]
+        sk: ExposedSecretKey<'a>,
+    }
+
+    #[derive(Deserialize)]
+    struct Import {
+        #[serde(deserialize_with = "serde_encoding::hex::deserialize")]
+        sk: SecretKey,
+    }
+
+    let mut rng = StdRng::seed_from_u64(0xbeef);
+    let sk = SecretKey::random(&mut rng);
+
+    let ser = serde_json::to_string(&Export {
+        sk: sk.expose_secret(),
+    })?;
+    assert!(ser.contains(&hex::encode(sk.expose_secret().to_bytes())));
+    let import: Import = serde_json::from_str(&ser)?;
+    assert_eq!(import.sk, sk);
+    Ok(())
+}
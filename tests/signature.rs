@@ -4,14 +4,7 @@
 //
 // Copyright (c) DUSK NETWORK. All rights reserved.
 
-use bls12_381_bls::{
-    MultisigPublicKey, MultisigSignature, PublicKey, SecretKey, Signature,
-};
-#[cfg(feature = "insecure-v1-signing")]
-use dusk_bls12_381::BlsScalar;
-#[cfg(feature = "insecure-v1-signing")]
-use dusk_bls12_381::{G1Affine, G1Projective};
-use dusk_bytes::Serializable;
+use bls12_381_bls::{MessageHasher, MultisigPublicKey, PublicKey, SecretKey};
 use rand::rngs::StdRng;
 use rand::{RngCore, SeedableRng};
 
@@ -69,166 +62,125 @@ fn secure_rejects_wrong_message_and_wrong_key() {
 }
 
 #[test]
-fn secure_signatures_are_not_valid_under_insecure_rules() {
-    let rng = &mut StdRng::seed_from_u64(0xdead);
-
+fn streamed_messages_sign_like_whole_messages() {
+    let rng = &mut StdRng::seed_from_u64(0x57ea);
     let sk = SecretKey::random(rng);
     let pk = PublicKey::from(&sk);
-    let msg = random_message(rng);
 
-    let sig = sk.sign(&msg);
+    let mut msg = vec![0u8; 10_000];
+    rng.fill_bytes(&mut msg);
+
+    let mut hasher = MessageHasher::new();
+    for chunk in msg.chunks(333) {
+        hasher.update(chunk);
+    }
+    let hashed = hasher.finalize();
+
+    let sig = sk.sign_hashed(&hashed);
+    assert_eq!(sig, sk.sign(&msg));
+    assert!(pk.verify_hashed(&sig, &hashed).is_ok());
     assert!(pk.verify(&sig, &msg).is_ok());
-    assert!(pk.verify_insecure(&sig, &msg).is_err());
 
-    let ms_sig = sk.sign_multisig(&pk, &msg);
-    let ms_pk_insecure = MultisigPublicKey::aggregate_insecure(&[pk])
-        .expect("insecure aggregation should succeed");
-    assert!(ms_pk_insecure.verify_insecure(&ms_sig, &msg).is_err());
+    let empty = MessageHasher::default().finalize();
+    assert_eq!(sk.sign_hashed(&empty), sk.sign(&[]));
+
+    let other = MessageHasher::new().chain(&msg[1..]).finalize();
+    assert!(pk.verify_hashed(&sig, &other).is_err());
 }
 
 #[test]
-fn insecure_legacy_vectors_remain_valid() {
-    const SINGLE_MSG: [u8; 100] = [
-        0x73, 0xff, 0x3f, 0xfc, 0x67, 0xe3, 0xc0, 0x8a, 0xc1, 0x1d, 0xf0, 0x30,
-        0x00, 0x02, 0x8f, 0x12, 0x47, 0x49, 0x52, 0x79, 0xe4, 0xe4, 0xbc, 0x89,
-        0xff, 0x7a, 0xcc, 0xbb, 0xb9, 0x44, 0x4e, 0x5f, 0xf3, 0x24, 0xc8, 0xbd,
-        0xef, 0xff, 0x0c, 0xb1, 0x53, 0xda, 0xdc, 0xa0, 0x70, 0xd9, 0x19, 0x7a,
-        0xb4, 0x56, 0xc4, 0x17, 0xa5, 0x16, 0x83, 0x60, 0x7f, 0x85, 0x6e, 0xe9,
-        0xd6, 0xac, 0xaa, 0xcf, 0x30, 0x3e, 0x24, 0xe7, 0x10, 0x8b, 0x12, 0x5b,
-        0x1e, 0x16, 0xf7, 0xec, 0xc9, 0xe5, 0x77, 0x31, 0x0f, 0x99, 0x51, 0x50,
-        0xd7, 0xbc, 0x58, 0x76, 0x5e, 0xc7, 0x56, 0xd3, 0xd3, 0x66, 0xc4, 0xf2,
-        0x6a, 0x47, 0x67, 0xe8,
-    ];
-    const SINGLE_PK: [u8; 96] = [
-        0xb8, 0x34, 0x2a, 0xba, 0x42, 0x3a, 0x84, 0x54, 0x3f, 0x42, 0xaa, 0xbf,
-        0xac, 0x36, 0x89, 0x3c, 0x52, 0x35, 0xe7, 0x80, 0x8c, 0xa8, 0x8e, 0xb4,
-        0xb7, 0x68, 0xa5, 0x23, 0x66, 0x75, 0xf9, 0xdb, 0x90, 0x33, 0xe5, 0x9a,
-        0x28, 0x13, 0x2b, 0xa5, 0x9d, 0x25, 0x6e, 0x3e, 0xe8, 0x78, 0xb1, 0xaf,
-        0x11, 0x4a, 0x1c, 0x6d, 0x47, 0x9d, 0x3a, 0x19, 0x25, 0x54, 0x4c, 0xf0,
-        0x1b, 0xce, 0xc9, 0x2f, 0x5f, 0x32, 0xd7, 0x0b, 0x4a, 0x50, 0xd1, 0xd0,
-        0x0f, 0xcf, 0x4e, 0x2d, 0x03, 0x5f, 0x9f, 0xa0, 0xe7, 0x7a, 0xf1, 0x53,
-        0x03, 0xfe, 0x23, 0x24, 0x9e, 0x1f, 0xc8, 0x5e, 0x9c, 0x7e, 0x3c, 0x79,
-    ];
-    const SINGLE_SIG: [u8; 48] = [
-        0x8e, 0x0c, 0xd9, 0x81, 0x1d, 0xcc, 0x78, 0xfa, 0xf6, 0x05, 0x25, 0x42,
-        0xb2, 0x87, 0xa0, 0x03, 0x3a, 0x2c, 0x52, 0x48, 0xb0, 0xdf, 0x0b, 0x41,
-        0x44, 0xb1, 0xb5, 0x56, 0xd6, 0x55, 0x23, 0x59, 0x04, 0x03, 0x68, 0xe7,
-        0xd4, 0xf1, 0x85, 0x1b, 0x81, 0x91, 0x04, 0xc0, 0xe0, 0x8d, 0x66, 0xcf,
-    ];
-    const MULTI_PK1: [u8; 96] = [
-        0x90, 0x1a, 0x8c, 0x70, 0xcb, 0x24, 0x3d, 0xbc, 0x9b, 0xc7, 0x01, 0x16,
-        0xa6, 0x3e, 0x6d, 0x43, 0x4b, 0xc9, 0x1e, 0xd3, 0x01, 0xee, 0x9e, 0xb9,
-        0xdf, 0x38, 0xaf, 0x87, 0x36, 0xec, 0xeb, 0xdd, 0x8c, 0x0b, 0xaa, 0x72,
-        0xf2, 0x96, 0xa5, 0xa5, 0xf5, 0x20, 0x14, 0xb0, 0xc7, 0xa1, 0xe3, 0x05,
-        0x01, 0xb9, 0x1c, 0x62, 0x9b, 0x21, 0xeb, 0x01, 0x20, 0xeb, 0xfc, 0x16,
-        0x9c, 0xbf, 0x8a, 0x47, 0x15, 0x1b, 0x6c, 0x22, 0x01, 0xed, 0x51, 0xa7,
-        0xbd, 0x76, 0xb5, 0x60, 0x07, 0x7a, 0xdc, 0x36, 0x37, 0xb1, 0xd2, 0xd1,
-        0x50, 0x4b, 0x2f, 0xea, 0xdd, 0x20, 0xae, 0x18, 0x70, 0x45, 0x34, 0x64,
-    ];
-    const MULTI_PK2: [u8; 96] = [
-        0x83, 0xaa, 0xbf, 0x4a, 0x7b, 0xb1, 0x9c, 0x3e, 0x94, 0xbd, 0x81, 0xc2,
-        0x4e, 0x17, 0xb8, 0x6d, 0xa5, 0x8a, 0xfc, 0x2c, 0x31, 0x2f, 0xef, 0x0f,
-        0x2f, 0x2e, 0x29, 0x98, 0x85, 0xbc, 0x94, 0xe1, 0x7d, 0xdf, 0x8f, 0x2c,
-        0x98, 0xb6, 0xf8, 0x36, 0x6a, 0xde, 0x21, 0xfb, 0x1a, 0x8b, 0x27, 0x93,
-        0x04, 0x1d, 0xcf, 0xa7, 0xe6, 0xeb, 0x63, 0x89, 0x81, 0x3a, 0x5f, 0x55,
-        0x84, 0xea, 0x0d, 0x9b, 0x95, 0xda, 0x53, 0xe5, 0xa0, 0x55, 0x1c, 0x6b,
-        0x86, 0x1e, 0x35, 0x49, 0xe5, 0xc4, 0x23, 0xaf, 0x90, 0x42, 0xd4, 0x4d,
-        0x7d, 0x62, 0x87, 0x8d, 0xfd, 0x71, 0xdd, 0x6b, 0xa1, 0x8e, 0xcd, 0xa1,
-    ];
-    const MULTI_AGG_PK: [u8; 96] = [
-        0x94, 0x2d, 0x00, 0xb9, 0xb7, 0xd7, 0x10, 0x36, 0x30, 0xf0, 0x29, 0xb9,
-        0x1a, 0xfa, 0x19, 0xca, 0x58, 0x62, 0x56, 0xb2, 0x1c, 0x32, 0xdd, 0xa0,
-        0x96, 0x87, 0xcf, 0x8d, 0x1e, 0x0d, 0x32, 0x29, 0x3f, 0xa9, 0x31, 0xab,
-        0x87, 0x34, 0x26, 0x1b, 0x4c, 0x0a, 0x86, 0x54, 0x64, 0x43, 0x67, 0xe1,
-        0x02, 0x4e, 0xf8, 0xd3, 0x5b, 0xab, 0x25, 0xde, 0x9e, 0x6a, 0xdb, 0xf8,
-        0x8f, 0xdd, 0xf4, 0x84, 0x03, 0x22, 0xd8, 0x0d, 0x6c, 0x9e, 0x2a, 0x10,
-        0x89, 0x59, 0xee, 0x43, 0x71, 0x75, 0x76, 0x5a, 0x8d, 0xf8, 0x60, 0x8b,
-        0x0f, 0x8f, 0x61, 0x7d, 0xfb, 0x38, 0xf9, 0x07, 0x44, 0xf4, 0x38, 0x28,
-    ];
-    const MULTI_SIG: [u8; 48] = [
-        0x81, 0x18, 0x79, 0xac, 0x91, 0xa2, 0x77, 0x82, 0xa8, 0x7f, 0xe1, 0x76,
-        0x8f, 0xf1, 0xc4, 0xb8, 0x6c, 0x00, 0x9d, 0x45, 0x74, 0x2f, 0x1a, 0xb2,
-        0xd9, 0xca, 0x4f, 0xc9, 0x52, 0x01, 0x1f, 0xf5, 0x8d, 0xeb, 0x97, 0xd6,
-        0x31, 0x0d, 0x9f, 0xdb, 0xcf, 0x29, 0x06, 0xd7, 0x57, 0xaf, 0xdf, 0x10,
-    ];
-
-    let msg = SINGLE_MSG;
-
-    let pk = PublicKey::from_bytes(&SINGLE_PK)
-        .expect("legacy vector public key should deserialize");
-    let sig = Signature::from_bytes(&SINGLE_SIG)
-        .expect("legacy vector signature should deserialize");
-    assert!(pk.verify_insecure(&sig, &msg).is_ok());
-    assert!(pk.verify(&sig, &msg).is_err());
-
-    let pk1 = PublicKey::from_bytes(&MULTI_PK1)
-        .expect("legacy vector multisig key 1 should deserialize");
-    let pk2 = PublicKey::from_bytes(&MULTI_PK2)
-        .expect("legacy vector multisig key 2 should deserialize");
-    let expected_agg = MultisigPublicKey::from_bytes(&MULTI_AGG_PK)
-        .expect("legacy vector aggregate key should deserialize");
-    let agg = MultisigPublicKey::aggregate_insecure(&[pk1, pk2])
-        .expect("legacy insecure aggregation should succeed");
-    assert_eq!(agg, expected_agg);
-
-    let ms_sig = MultisigSignature::from_bytes(&MULTI_SIG)
-        .expect("legacy vector multisig signature should deserialize");
-    assert!(agg.verify_insecure(&ms_sig, &msg).is_ok());
-    assert!(agg.verify(&ms_sig, &msg).is_err());
+fn prehashed_signatures_are_domain_separated() {
+    let rng = &mut StdRng::seed_from_u64(0x9e4a);
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+
+    let mut digest = [0u8; 32];
+    rng.fill_bytes(&mut digest);
+
+    let sig = sk.sign_prehashed(&digest);
+    assert!(pk.verify_prehashed(&sig, &digest).is_ok());
+    assert!(pk.verify(&sig, &digest).is_err());
+
+    let plain = sk.sign(&digest);
+    assert_ne!(sig, plain);
+    assert!(pk.verify_prehashed(&plain, &digest).is_err());
 }
 
 #[test]
-#[cfg(feature = "insecure-v1-signing")]
-fn insecure_signatures_are_not_valid_under_secure_rules() {
-    let rng = &mut StdRng::seed_from_u64(0xcafe);
+#[cfg(feature = "hardened")]
+fn hardened_signatures_match_default_signatures() {
+    let rng = &mut StdRng::seed_from_u64(0xf00d);
 
     let sk = SecretKey::random(rng);
     let pk = PublicKey::from(&sk);
     let msg = random_message(rng);
 
-    let sig = sk.sign_insecure(&msg);
-    assert!(pk.verify_insecure(&sig, &msg).is_ok());
-    assert!(pk.verify(&sig, &msg).is_err());
-
-    let ms_sig = sk.sign_multisig_insecure(&pk, &msg);
-    let ms_pk_insecure = MultisigPublicKey::aggregate_insecure(&[pk])
-        .expect("insecure aggregation should succeed");
-    assert!(ms_pk_insecure.verify_insecure(&ms_sig, &msg).is_ok());
+    let sig = sk
+        .sign_hardened(rng, &pk, &msg)
+        .expect("hardened signing should succeed");
+    assert_eq!(sig, sk.sign(&msg));
+    assert!(pk.verify(&sig, &msg).is_ok());
 
-    let ms_pk_secure = MultisigPublicKey::aggregate(&[pk])
+    let ms_sig = sk
+        .sign_multisig_hardened(rng, &pk, &msg)
+        .expect("hardened multisig signing should succeed");
+    assert_eq!(ms_sig, sk.sign_multisig(&pk, &msg));
+    let ms_pk = MultisigPublicKey::aggregate(&[pk])
         .expect("aggregation should succeed");
-    assert!(ms_pk_secure.verify(&ms_sig, &msg).is_err());
+    assert!(ms_pk.verify(&ms_sig, &msg).is_ok());
 }
 
 #[test]
-#[cfg(feature = "insecure-v1-signing")]
-fn insecure_linear_forgery_is_rejected_by_secure_verifier() {
-    let rng = &mut StdRng::seed_from_u64(0x1337);
+#[cfg(feature = "hardened")]
+fn hardened_multisig_rejects_mismatched_key() {
+    let rng = &mut StdRng::seed_from_u64(0xabad1dea);
 
     let sk = SecretKey::random(rng);
-    let pk = PublicKey::from(&sk);
+    let other_pk = PublicKey::from(&SecretKey::random(rng));
+    let msg = random_message(rng);
 
-    let msg1 = random_message(rng);
-    let msg2 = random_message(rng);
-    let msg3 = random_message(rng);
+    assert_eq!(
+        sk.sign_multisig_hardened(rng, &other_pk, &msg).unwrap_err(),
+        bls12_381_bls::Error::SelfCheckFailed
+    );
+}
 
-    let sig1 = sk.sign_insecure(&msg1);
-    let sig2 = sk.sign_insecure(&msg2);
+#[test]
+#[cfg(feature = "hardened")]
+fn hardened_detects_corrupted_scalar() {
+    let rng = &mut StdRng::seed_from_u64(0xfa17);
+
+    // A scalar corrupted in memory no longer matches the stored public key.
+    let sk = SecretKey::random(rng);
+    let pk = PublicKey::from(&sk);
+    let corrupted = SecretKey::random(rng);
+    let msg = random_message(rng);
+
+    assert_eq!(
+        corrupted.sign_hardened(rng, &pk, &msg).unwrap_err(),
+        bls12_381_bls::Error::SelfCheckFailed
+    );
+    assert_eq!(
+        corrupted
+            .sign_multisig_hardened(rng, &pk, &msg)
+            .unwrap_err(),
+        bls12_381_bls::Error::SelfCheckFailed
+    );
+}
 
-    let h1 = BlsScalar::hash_to_scalar(&msg1);
-    let h2 = nonzero_hash(&msg2);
-    let h3 = BlsScalar::hash_to_scalar(&msg3);
+#[test]
+#[cfg(feature = "hardened")]
+fn hardened_keypair_signatures() {
+    let rng = &mut StdRng::seed_from_u64(0x5eed);
 
-    // Choose a = 1 and solve b such that h1 + b*h2 = h3 (mod r).
-    let b = (h3 - h1) * h2.invert().expect("non-zero scalar must invert");
+    let keypair = bls12_381_bls::Keypair::random(rng);
+    let msg = random_message(rng);
 
-    let s1 = sig_to_projective(&sig1);
-    let s2 = sig_to_projective(&sig2);
-    let forged = s1 + s2 * b;
-    let forged = signature_from_projective(forged);
+    let sig = keypair.sign_hardened(rng, &msg).unwrap();
+    assert_eq!(sig, keypair.sign(&msg));
 
-    assert!(pk.verify_insecure(&forged, &msg3).is_ok());
-    assert!(pk.verify(&forged, &msg3).is_err());
+    let ms_sig = keypair.sign_multisig_hardened(rng, &msg).unwrap();
+    assert_eq!(ms_sig, keypair.sign_multisig(&msg));
 }
 
 fn random_message(rng: &mut StdRng) -> [u8; 100] {
@@ -236,26 +188,3 @@ fn random_message(rng: &mut StdRng) -> [u8; 100] {
     rng.fill_bytes(&mut msg);
     msg
 }
-
-#[cfg(feature = "insecure-v1-signing")]
-fn sig_to_projective(sig: &Signature) -> G1Projective {
-    let bytes = sig.to_bytes();
-    let affine = G1Affine::from_bytes(&bytes).expect("signature bytes valid");
-    G1Projective::from(affine)
-}
-
-#[cfg(feature = "insecure-v1-signing")]
-fn signature_from_projective(p: G1Projective) -> Signature {
-    let affine: G1Affine = p.into();
-    Signature::from_bytes(&affine.to_bytes())
-        .expect("constructed projective should serialize to signature")
-}
-
-#[cfg(feature = "insecure-v1-signing")]
-fn nonzero_hash(msg: &[u8]) -> BlsScalar {
-    let mut h = BlsScalar::hash_to_scalar(msg);
-    if h.is_zero().into() {
-        h = BlsScalar::one();
-    }
-    h
-}
@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "signature-traits")]
+
+use bls12_381_bls::{
+    Keypair, MultisigPublicKey, MultisigSignature, PublicKey, SecretKey,
+    Signature,
+};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use sha2_v010::{Digest, Sha256};
+use signature::{
+    DigestSigner, DigestVerifier, Keypair as _, SignatureEncoding, Signer,
+    Verifier,
+};
+
+fn sign_generic<S: Signer<Signature>>(signer: &S, msg: &[u8]) -> Signature {
+    signer.sign(msg)
+}
+
+fn verify_generic<V: Verifier<Signature>>(
+    verifier: &V,
+    msg: &[u8],
+    sig: &Signature,
+) -> bool {
+    verifier.verify(msg, sig).is_ok()
+}
+
+#[test]
+fn signer_and_verifier() {
+    let mut rng = StdRng::seed_from_u64(0x5167);
+    let sk = SecretKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg = b"a message";
+
+    let sig = sign_generic(&sk, msg);
+    assert_eq!(sig, sk.sign(msg));
+    assert!(verify_generic(&pk, msg, &sig));
+    assert!(!verify_generic(&pk, b"another message", &sig));
+
+    let keypair = Keypair::from(&sk);
+    assert_eq!(keypair.verifying_key(), pk);
+    assert_eq!(sign_generic(&keypair, msg), sig);
+}
+
+#[test]
+fn multisig_signer_and_verifier() {
+    let mut rng = StdRng::seed_from_u64(0x5167);
+    let keypair = Keypair::random(&mut rng);
+    let msg = b"a message";
+
+    let sig: MultisigSignature = Signer::sign(&keypair, msg);
+    let apk = MultisigPublicKey::aggregate(&[*keypair.public_key()]).unwrap();
+    assert!(Verifier::verify(&apk, msg, &sig).is_ok());
+    assert!(Verifier::verify(&apk, b"another message", &sig).is_err());
+}
+
+#[test]
+fn digest_signer_and_verifier() {
+    let mut rng = StdRng::seed_from_u64(0x5167);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
+
+    let sig = sk.sign_digest(Sha256::new_with_prefix(msg));
+    assert!(pk.verify_digest(Sha256::new_with_prefix(msg), &sig).is_ok());
+    assert!(
+        pk.verify_digest(Sha256::new_with_prefix(b"another message"), &sig)
+            .is_err()
+    );
+
+    // A digest signature is not a plain signature over the digest bytes.
+    let digest = Sha256::digest(msg);
+    assert!(Verifier::verify(&pk, &digest, &sig).is_err());
+    assert_ne!(sig, sk.sign(&digest));
+}
+
+#[test]
+fn signature_encoding() {
+    let mut rng = StdRng::seed_from_u64(0x5167);
+    let keypair = Keypair::random(&mut rng);
+    let msg = b"a message";
+
+    let sig: Signature = Signer::sign(&keypair, msg);
+    let bytes = SignatureEncoding::to_bytes(&sig);
+    assert_eq!(Signature::try_from(&bytes[..]).unwrap(), sig);
+    assert_eq!(sig.encoded_len(), 48);
+    assert!(Signature::try_from(&bytes[1..]).is_err());
+
+    let sig: MultisigSignature = Signer::sign(&keypair, msg);
+    let bytes = SignatureEncoding::to_bytes(&sig);
+    assert_eq!(MultisigSignature::try_from(&bytes[..]).unwrap(), sig);
+}
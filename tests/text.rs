@@ -0,0 +1,89 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#![cfg(feature = "bech32")]
+
+use bls12_381_bls::{
+    Error, MultisigPublicKey, MultisigSignature, PublicKey, SecretKey,
+};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn text_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0xb3c4);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let apk = MultisigPublicKey::aggregate(&[pk]).unwrap();
+    let sig = sk.sign(b"a message");
+    let ms_sig = sk.sign_multisig(&pk, b"a message");
+
+    let s = pk.to_string();
+    assert!(s.starts_with("blspk1"));
+    assert_eq!(s.parse(), Ok(pk));
+    assert_eq!(s.to_uppercase().parse(), Ok(pk));
+
+    let s = apk.to_string();
+    assert!(s.starts_with("blsapk1"));
+    assert_eq!(s.parse(), Ok(apk));
+
+    let s = sig.to_string();
+    assert!(s.starts_with("blssig1"));
+    assert_eq!(s.parse(), Ok(sig));
+
+    let s = ms_sig.to_string();
+    assert!(s.starts_with("blsmsig1"));
+    assert_eq!(s.parse(), Ok(ms_sig));
+
+    let s = sk.expose_secret().to_string();
+    assert!(s.starts_with("blssk1"));
+    assert_eq!(s.parse::<SecretKey>(), Ok(sk));
+}
+
+#[test]
+fn text_rejects_wrong_type_and_typos() {
+    let mut rng = StdRng::seed_from_u64(0xb3c4);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let apk = MultisigPublicKey::aggregate(&[pk]).unwrap();
+    let sig = sk.sign(b"a message");
+
+    assert_eq!(
+        apk.to_string().parse::<PublicKey>(),
+        Err(Error::WrongPrefix)
+    );
+    assert_eq!(
+        pk.to_string().parse::<MultisigPublicKey>(),
+        Err(Error::WrongPrefix)
+    );
+    assert_eq!(
+        sig.to_string().parse::<MultisigSignature>(),
+        Err(Error::WrongPrefix)
+    );
+
+    let mut typo = pk.to_string().into_bytes();
+    let i = typo.len() / 2;
+    typo[i] = if typo[i] == b'q' { b'p' } else { b'q' };
+    let typo = String::from_utf8(typo).unwrap();
+    assert_eq!(typo.parse::<PublicKey>(), Err(Error::InvalidEncoding));
+
+    assert!("blspk1".parse::<PublicKey>().is_err());
+}
+
+#[test]
+fn fingerprints() {
+    let mut rng = StdRng::seed_from_u64(0xb3c4);
+    let pk1 = PublicKey::from(&SecretKey::random(&mut rng));
+    let pk2 = PublicKey::from(&SecretKey::random(&mut rng));
+
+    let fingerprint = pk1.fingerprint();
+    assert_eq!(fingerprint.to_string().len(), 16);
+    assert_eq!(fingerprint, pk1.fingerprint());
+    assert_ne!(fingerprint, pk2.fingerprint());
+
+    let apk = MultisigPublicKey::aggregate(&[pk1]).unwrap();
+    assert_ne!(apk.fingerprint(), fingerprint);
+}
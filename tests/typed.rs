@@ -0,0 +1,132 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use bls12_381_bls::{
+    MessageEncoder, PublicKey, SecretKey, SignableMessage,
+    impl_signable_message,
+};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+struct Vote {
+    round: u64,
+    step: u8,
+    block_hash: [u8; 32],
+}
+
+impl_signable_message!(Vote, b"dusk/vote", 1, [round, step, block_hash]);
+
+// Same layout as `Vote`, under another domain.
+struct Attestation {
+    round: u64,
+    step: u8,
+    block_hash: [u8; 32],
+}
+
+impl_signable_message!(
+    Attestation,
+    b"dusk/attestation",
+    1,
+    [round, step, block_hash]
+);
+
+// Same domain as `Vote`, with a newer version.
+struct VoteV2 {
+    round: u64,
+    step: u8,
+    block_hash: [u8; 32],
+}
+
+impl_signable_message!(VoteV2, b"dusk/vote", 2, [round, step, block_hash]);
+
+struct Memo {
+    text: String,
+    payload: Vec<u8>,
+}
+
+impl SignableMessage for Memo {
+    const DOMAIN: &'static [u8] = b"dusk/memo";
+    const VERSION: u16 = 1;
+
+    fn encode(&self, encoder: &mut MessageEncoder) {
+        encoder.write(self.text.as_str());
+        encoder.write(&self.payload);
+    }
+}
+
+#[test]
+fn typed_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(0x7e5d);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+
+    let vote = Vote {
+        round: 42,
+        step: 3,
+        block_hash: [0xab; 32],
+    };
+    let sig = sk.sign_typed(&vote);
+    assert!(pk.verify_typed(&sig, &vote).is_ok());
+
+    let other_vote = Vote { round: 43, ..vote };
+    assert!(pk.verify_typed(&sig, &other_vote).is_err());
+}
+
+#[test]
+fn typed_signatures_are_domain_separated() {
+    let mut rng = StdRng::seed_from_u64(0x7e5d);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+
+    let vote = Vote {
+        round: 42,
+        step: 3,
+        block_hash: [0xab; 32],
+    };
+    let sig = sk.sign_typed(&vote);
+
+    let attestation = Attestation {
+        round: vote.round,
+        step: vote.step,
+        block_hash: vote.block_hash,
+    };
+    assert!(pk.verify_typed(&sig, &attestation).is_err());
+
+    let vote_v2 = VoteV2 {
+        round: vote.round,
+        step: vote.step,
+        block_hash: vote.block_hash,
+    };
+    assert!(pk.verify_typed(&sig, &vote_v2).is_err());
+
+    // The raw encoding of the fields doesn't verify as a plain message.
+    let mut raw = Vec::new();
+    raw.extend_from_slice(&vote.round.to_be_bytes());
+    raw.push(vote.step);
+    raw.extend_from_slice(&vote.block_hash);
+    assert!(pk.verify(&sig, &raw).is_err());
+    assert!(pk.verify_typed(&sk.sign(&raw), &vote).is_err());
+}
+
+#[test]
+fn typed_variable_length_fields_are_unambiguous() {
+    let mut rng = StdRng::seed_from_u64(0x7e5d);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+
+    let memo = Memo {
+        text: "ab".into(),
+        payload: b"c".to_vec(),
+    };
+    let shifted = Memo {
+        text: "a".into(),
+        payload: b"bc".to_vec(),
+    };
+
+    let sig = sk.sign_typed(&memo);
+    assert!(pk.verify_typed(&sig, &memo).is_ok());
+    assert!(pk.verify_typed(&sig, &shifted).is_err());
+}
@@ -0,0 +1,157 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+#[cfg(feature = "insecure-v1-signing")]
+use bls12_381_bls::legacy;
+use bls12_381_bls::{
+    Error, PublicKey, SecretKey, SignatureScheme, VersionPolicy,
+    VersionedMultisigSignature, VersionedSignature,
+};
+use dusk_bytes::Serializable;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn versioned_encoding() {
+    let mut rng = StdRng::seed_from_u64(0x7a9);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+
+    let sig = VersionedSignature::from(sk.sign(b"a message"));
+    let bytes = sig.to_bytes();
+    assert_eq!(bytes[0], SignatureScheme::V2.tag());
+    assert_eq!(VersionedSignature::from_bytes(&bytes).unwrap(), sig);
+
+    let ms_sig =
+        VersionedMultisigSignature::from(sk.sign_multisig(&pk, b"a message"));
+    let mut bytes = ms_sig.to_bytes();
+    assert_eq!(
+        VersionedMultisigSignature::from_bytes(&bytes).unwrap(),
+        ms_sig
+    );
+
+    // v1 signatures decode, but don't hand out a v2 signature.
+    bytes[0] = SignatureScheme::V1Insecure.tag();
+    let v1 = VersionedMultisigSignature::from_bytes(&bytes).unwrap();
+    assert_eq!(v1.scheme(), SignatureScheme::V1Insecure);
+    assert!(v1.signature().is_none());
+    assert_eq!(v1.to_bytes(), bytes);
+
+    let mut unknown = bytes;
+    unknown[0] = 0xff;
+    assert!(VersionedMultisigSignature::from_bytes(&unknown).is_err());
+}
+
+#[test]
+fn verify_any_dispatches_secure_signatures() {
+    let mut rng = StdRng::seed_from_u64(0x7a9);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
+
+    let sig = VersionedSignature::from(sk.sign(msg));
+    assert_eq!(
+        pk.verify_any(&sig, msg, &VersionPolicy::SECURE_ONLY, 0),
+        Ok(SignatureScheme::V2)
+    );
+    assert_eq!(
+        pk.verify_any(&sig, b"another message", &VersionPolicy::ANY, 0),
+        Err(Error::InvalidSignature)
+    );
+
+    // Secure signatures mislabeled as v1 don't verify.
+    let mislabeled = relabel_v1(&sig);
+    #[cfg(feature = "insecure-v1-verify")]
+    let expected = Err(Error::InvalidSignature);
+    #[cfg(not(feature = "insecure-v1-verify"))]
+    let expected = Err(Error::SchemeNotAccepted);
+    assert_eq!(
+        pk.verify_any(&mislabeled, msg, &VersionPolicy::ANY, 0),
+        expected
+    );
+
+    let ms_sig = VersionedMultisigSignature::from(sk.sign_multisig(&pk, msg));
+    assert_eq!(
+        ms_sig.verify_any(&[pk], msg, &VersionPolicy::default(), 0),
+        Ok(SignatureScheme::V2)
+    );
+}
+
+#[test]
+fn version_policy_refuses_v1_after_cutoff() {
+    let mut rng = StdRng::seed_from_u64(0x7a9);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
+
+    let v2 = VersionedSignature::from(sk.sign(msg));
+    let v1 = relabel_v1(&v2);
+
+    let policy = VersionPolicy::v1_cutoff(100);
+    assert!(!policy.accepts(SignatureScheme::V1Insecure, 100));
+    assert!(policy.accepts(SignatureScheme::V1Insecure, 99));
+    assert_eq!(
+        pk.verify_any(&v1, msg, &policy, 100),
+        Err(Error::SchemeNotAccepted)
+    );
+    assert_eq!(
+        pk.verify_any(&v2, msg, &policy, 100),
+        Ok(SignatureScheme::V2)
+    );
+
+    assert!(!VersionPolicy::default().accepts(SignatureScheme::V1Insecure, 0));
+    assert!(VersionPolicy::ANY.accepts(SignatureScheme::V1Insecure, u64::MAX));
+}
+
+#[test]
+#[cfg(feature = "insecure-v1-signing")]
+fn verify_any_dispatches_insecure_signatures() {
+    let mut rng = StdRng::seed_from_u64(0x7a9);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
+
+    let sig = VersionedSignature::from(sk.sign_insecure(msg));
+    assert!(sig.signature().is_none());
+    assert_eq!(sig.legacy_signature(), Some(sk.sign_insecure(msg)));
+    assert_eq!(
+        pk.verify_any(&sig, msg, &VersionPolicy::ANY, 0),
+        Ok(SignatureScheme::V1Insecure)
+    );
+    assert_eq!(
+        pk.verify_any(&sig, msg, &VersionPolicy::SECURE_ONLY, 0),
+        Err(Error::SchemeNotAccepted)
+    );
+
+    let ms_sig = VersionedMultisigSignature::from(
+        sk.sign_multisig_insecure(&legacy::PublicKey::from(&pk), msg),
+    );
+    assert_eq!(
+        ms_sig.verify_any(&[pk], msg, &VersionPolicy::v1_cutoff(10), 5),
+        Ok(SignatureScheme::V1Insecure)
+    );
+}
+
+#[test]
+#[cfg(not(feature = "insecure-v1-verify"))]
+fn verify_any_refuses_v1_without_feature() {
+    let mut rng = StdRng::seed_from_u64(0x7a9);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let msg = b"a message";
+
+    let v1 = relabel_v1(&VersionedSignature::from(sk.sign(msg)));
+    assert_eq!(
+        pk.verify_any(&v1, msg, &VersionPolicy::ANY, 0),
+        Err(Error::SchemeNotAccepted)
+    );
+}
+
+fn relabel_v1(sig: &VersionedSignature) -> VersionedSignature {
+    let mut bytes = sig.to_bytes();
+    bytes[0] = SignatureScheme::V1Insecure.tag();
+    VersionedSignature::from_bytes(&bytes).unwrap()
+}
@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) DUSK NETWORK. All rights reserved.
+
+use bls12_381_bls::{Error, PublicKey, SecretKey, VrfProof};
+use dusk_bytes::Serializable;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+#[test]
+fn prove_and_verify() {
+    let mut rng = StdRng::seed_from_u64(0x5f7);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let input = b"round 42";
+
+    let (output, proof) = sk.vrf_prove(input);
+    assert_eq!(pk.vrf_verify(input, &proof), Ok(output));
+    assert_eq!(proof.output(), output);
+
+    // The output is unique for a key and input.
+    assert_eq!(sk.vrf_prove(input), (output, proof));
+    let (other_output, _) = sk.vrf_prove(b"round 43");
+    assert_ne!(other_output, output);
+
+    let proof = VrfProof::from_bytes(&proof.to_bytes()).unwrap();
+    assert!(proof.is_valid());
+    assert_eq!(pk.vrf_verify(input, &proof), Ok(output));
+}
+
+#[test]
+fn rejects_wrong_proofs() {
+    let mut rng = StdRng::seed_from_u64(0x5f7);
+    let sk = SecretKey::random(&mut rng);
+    let pk = PublicKey::from(&sk);
+    let input = b"round 42";
+    let (_, proof) = sk.vrf_prove(input);
+
+    assert_eq!(
+        pk.vrf_verify(b"round 43", &proof),
+        Err(Error::InvalidSignature)
+    );
+    let other = PublicKey::from(&SecretKey::random(&mut rng));
+    assert_eq!(
+        other.vrf_verify(input, &proof),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        pk.vrf_verify(input, &VrfProof::default()),
+        Err(Error::IdentityPoint)
+    );
+}
+
+#[test]
+fn proofs_are_not_signatures() {
+    let mut rng = StdRng::seed_from_u64(0x5f7);
+    let sk = SecretKey::random(&mut rng);
+    let input = b"round 42";
+
+    let (_, proof) = sk.vrf_prove(input);
+    assert_ne!(proof.to_bytes(), sk.sign(input).to_bytes());
+}